@@ -0,0 +1,52 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, fmt::Write, fs, path::Path};
+
+/// Generates the solution registry used by the `all` runner.
+/// Every `src/bin/NN.rs` is included as a module so that all days can run in a single process.
+/// The modules are left out of the runner's tests, the days' tests already run as part of their own binaries.
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let bin_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/bin");
+
+    let mut days: Vec<u8> = fs::read_dir(&bin_dir)
+        .expect("could not read src/bin")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+            if path.extension()? == "rs" && stem.len() == 2 {
+                stem.parse().ok()
+            } else {
+                None
+            }
+        })
+        .collect();
+    days.sort_unstable();
+
+    let mut registry = String::new();
+
+    for day in &days {
+        let module_path = bin_dir.join(format!("{:02}.rs", day));
+        writeln!(
+            registry,
+            "#[cfg(not(test))]\n#[path = {:?}]\n#[allow(dead_code)]\nmod day_{:02};",
+            module_path, day
+        )
+        .unwrap();
+    }
+
+    registry
+        .push_str("\n#[cfg(test)]\nstatic SOLUTIONS: &[&dyn advent_of_code::Solution] = &[];\n");
+    registry
+        .push_str("\n#[cfg(not(test))]\nstatic SOLUTIONS: &[&dyn advent_of_code::Solution] = &[\n");
+    for day in &days {
+        writeln!(registry, "    &day_{:02}::Day,", day).unwrap();
+    }
+    registry.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, registry).expect("could not write solution registry");
+}
@@ -0,0 +1,179 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::report::{Outcome, PartResult};
+use crate::spans;
+use std::time::Duration;
+
+const DEFAULT_ITERATIONS: usize = 100;
+const DEFAULT_WARMUP: usize = 5;
+
+/// How often a part is run in benchmark mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+    pub iterations: usize,
+    pub warmup: usize,
+}
+
+impl Bench {
+    /// Runs `solver` `warmup` times without recording, then `iterations` times.
+    /// The returned result carries the [`Stats`] of the recorded runs, their median as `elapsed`,
+    /// and their spans averaged per run.
+    /// Stops at the first run that does not solve the part and returns its outcome.
+    pub fn run(&self, mut solver: impl FnMut() -> Outcome) -> Outcome {
+        for _ in 0..self.warmup {
+            let outcome = solver();
+            if outcome.result().is_none() {
+                return outcome;
+            }
+        }
+
+        let mut samples = Vec::with_capacity(self.iterations);
+        let mut span_samples = Vec::with_capacity(self.iterations);
+        let mut result = None;
+        for _ in 0..self.iterations {
+            match solver() {
+                Outcome::Solved(mut sample) => {
+                    samples.push(sample.elapsed);
+                    span_samples.push(std::mem::take(&mut sample.spans));
+                    result = Some(sample);
+                }
+                outcome => return outcome,
+            }
+        }
+
+        match (result, Stats::from_samples(&mut samples)) {
+            (Some(result), Some(stats)) => Outcome::Solved(PartResult {
+                elapsed: stats.median,
+                stats: Some(stats),
+                spans: spans::mean(&span_samples),
+                ..result
+            }),
+            _ => Outcome::NotSolved,
+        }
+    }
+}
+
+/// Runs `solver` once, or repeatedly if `bench` is set.
+pub fn measure(bench: Option<Bench>, mut solver: impl FnMut() -> Outcome) -> Outcome {
+    match bench {
+        Some(bench) => bench.run(solver),
+        None => solver(),
+    }
+}
+
+/// Parses `--bench`, `--iterations` and `--warmup`. Returns `None` unless `--bench` is passed.
+pub fn parse_args(args: &mut pico_args::Arguments) -> Result<Option<Bench>, pico_args::Error> {
+    let bench = args.contains("--bench");
+    let iterations = args.opt_value_from_fn("--iterations", parse_iterations)?;
+    let warmup = args.opt_value_from_str("--warmup")?;
+
+    Ok(bench.then(|| Bench {
+        iterations: iterations.unwrap_or(DEFAULT_ITERATIONS),
+        warmup: warmup.unwrap_or(DEFAULT_WARMUP),
+    }))
+}
+
+fn parse_iterations(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(iterations) if iterations > 0 => Ok(iterations),
+        _ => Err(format!(
+            "invalid iterations \"{}\", expected a positive number",
+            s
+        )),
+    }
+}
+
+/// Summary of the timings of repeated runs of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples.
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_unstable();
+
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        let mean = samples.iter().sum::<Duration>() / len as u32;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        // nearest-rank percentile.
+        let p95 = samples[(len * 95).div_ceil(100) - 1];
+
+        Some(Stats {
+            iterations: len,
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples: Vec<Duration> = [5, 1, 4, 2, 3, 20]
+            .into_iter()
+            .map(Duration::from_micros)
+            .collect();
+        let stats = Stats::from_samples(&mut samples).unwrap();
+
+        assert_eq!(stats.iterations, 6);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(
+            stats.median,
+            Duration::from_micros(3) + Duration::from_nanos(500)
+        );
+        assert_eq!(stats.mean, Duration::from_micros(35) / 6);
+        assert_eq!(stats.p95, Duration::from_micros(20));
+        assert!(stats.stddev > Duration::from_micros(6) && stats.stddev < Duration::from_micros(7));
+
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_bench_run() {
+        let bench = Bench {
+            iterations: 3,
+            warmup: 2,
+        };
+
+        let mut calls = 0;
+        let outcome = bench.run(|| {
+            calls += 1;
+            crate::run_part(1, 1, |_| Some(calls), "")
+        });
+
+        let result = outcome.result().unwrap();
+        assert_eq!(calls, 5);
+        assert_eq!(result.answer, "5");
+        assert_eq!(result.stats.unwrap().iterations, 3);
+        assert_eq!(bench.run(|| Outcome::NotSolved), Outcome::NotSolved);
+    }
+}
@@ -1,31 +1,30 @@
-fn get_elf_calories(input: &str) -> Vec<u32> {
-    let elves = input.split("\n\n");
-    elves
-        .map(|elf| {
-            elf.split('\n').fold(0, |total, calories| {
-                total + calories.parse::<u32>().unwrap()
-            })
-        })
-        .collect()
+use advent_of_code::helpers::{parse_blocks, ParseError};
+
+fn get_elf_calories(input: &str) -> Result<Vec<u32>, ParseError> {
+    let elves = parse_blocks(input, str::parse::<u32>)?;
+    Ok(elves.into_iter().map(|elf| elf.into_iter().sum()).collect())
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    get_elf_calories(input).into_iter().max()
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    Ok(get_elf_calories(input)?
+        .into_iter()
+        .max()
+        .unwrap_or_default())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut elf_calories = get_elf_calories(input);
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    let mut elf_calories = get_elf_calories(input)?;
     elf_calories.sort_by(|a, b| b.cmp(a));
-    elf_calories
-        .into_iter()
-        .take(3)
-        .fold(Some(0), |acc, item| Some(acc.unwrap_or(0) + item))
+    Ok(elf_calories.into_iter().take(3).sum())
 }
 
+advent_of_code::solution!(1);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
+    let input = &advent_of_code::read_input(&Day);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
+    advent_of_code::exit_on_failure();
 }
 
 #[cfg(test)]
@@ -33,14 +32,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_one(&input), Some(69281));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
-        assert_eq!(part_two(&input), Some(201524));
+    fn test_examples() {
+        advent_of_code::check_examples(&Day);
     }
 }
@@ -126,10 +126,13 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(score)
 }
 
+advent_of_code::solution!(2);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
+    let input = &advent_of_code::read_input(&Day);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
+    advent_of_code::exit_on_failure();
 }
 
 #[cfg(test)]
@@ -137,14 +140,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), None);
+    fn test_examples() {
+        advent_of_code::check_examples(&Day);
     }
 }
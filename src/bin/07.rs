@@ -1,17 +1,20 @@
-use std::{borrow::Borrow, cell::RefCell, collections::HashMap, rc::Rc};
+use advent_of_code::helpers::ParseError;
+use std::{borrow::Borrow, cell::RefCell, collections::HashMap, num::ParseIntError, rc::Rc};
 
 #[derive(Debug)]
-struct FileSystem {
+pub struct FileSystem {
     root: Rc<Directory>,
 }
 
 #[derive(Debug)]
 struct File {
+    #[allow(dead_code)]
     name: String,
     size: usize,
 }
 #[derive(Debug)]
 struct Directory {
+    #[allow(dead_code)]
     name: String,
     parent: Option<Rc<Directory>>,
     subdir: RefCell<HashMap<String, Rc<Directory>>>,
@@ -32,14 +35,12 @@ impl Directory {
         self.subdir
             .borrow()
             .values()
-            .into_iter()
-            .map(|subdir| {
+            .flat_map(|subdir| {
                 let mut vec = Vec::new();
                 vec.push(Rc::clone(subdir));
                 vec.append(subdir.get_subdirs().as_mut());
                 vec
             })
-            .flatten()
             .collect()
     }
 }
@@ -52,19 +53,33 @@ impl FileSystem {
         }
     }
 
-    fn parse_input(&mut self, input: &str) -> &Self {
-        let lines = input.lines();
+    fn parse_input(&mut self, input: &str) -> Result<(), ParseError> {
         let mut pwd = Rc::clone(&self.root);
 
-        for line in lines {
+        for (index, line) in input.lines().enumerate() {
+            let error = |message: &str| ParseError {
+                line: index + 1,
+                content: line.to_string(),
+                message: message.to_string(),
+            };
+
             let words = line.split(' ').collect::<Vec<&str>>();
-            match (words[0], words[1]) {
+            match (words[0], words.get(1).copied().unwrap_or_default()) {
                 ("$", "ls") => {}
                 ("$", "cd") => {
-                    pwd = match words[2] {
+                    pwd = match words.get(2).copied().unwrap_or_default() {
                         "/" => Rc::clone(&self.root),
-                        ".." => Rc::clone(&pwd.parent.as_ref().unwrap()),
-                        dirname => pwd.subdir.borrow().get(dirname).unwrap().clone(),
+                        ".." => Rc::clone(
+                            pwd.parent
+                                .as_ref()
+                                .ok_or_else(|| error("the root directory has no parent"))?,
+                        ),
+                        dirname => pwd
+                            .subdir
+                            .borrow()
+                            .get(dirname)
+                            .ok_or_else(|| error("unknown directory"))?
+                            .clone(),
                     };
                 }
                 ("dir", dirname) => {
@@ -73,8 +88,6 @@ impl FileSystem {
                             dirname.to_owned(),
                             Rc::new(Directory::new(dirname, Some(Rc::clone(&pwd)))),
                         );
-                    } else {
-                        ()
                     }
                 }
                 (size, filename) => {
@@ -83,31 +96,24 @@ impl FileSystem {
                             filename.to_owned(),
                             Rc::new(File {
                                 name: filename.to_owned(),
-                                size: size.parse().unwrap(),
+                                size: size
+                                    .parse()
+                                    .map_err(|e: ParseIntError| error(&e.to_string()))?,
                             }),
                         );
-                    } else {
-                        ()
                     }
                 }
             }
         }
-        self
+        Ok(())
     }
 
     fn get_dir_size(dir: &Directory) -> usize {
-        let direct: usize = dir
-            .files
-            .borrow()
-            .values()
-            .into_iter()
-            .map(|file| file.size)
-            .sum();
+        let direct: usize = dir.files.borrow().values().map(|file| file.size).sum();
         let children: usize = dir
             .subdir
             .borrow()
             .values()
-            .into_iter()
             .map(|subdir| FileSystem::get_dir_size(subdir))
             .sum();
         direct + children
@@ -118,9 +124,13 @@ impl FileSystem {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn parse(input: &str) -> Result<FileSystem, ParseError> {
     let mut file_system = FileSystem::new();
-    file_system.parse_input(input);
+    file_system.parse_input(input)?;
+    Ok(file_system)
+}
+
+pub fn part_one(file_system: &FileSystem) -> Option<u32> {
     let sized_dirs: usize = file_system
         .get_dirs()
         .into_iter()
@@ -130,11 +140,9 @@ pub fn part_one(input: &str) -> Option<u32> {
     Some(sized_dirs as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut file_system = FileSystem::new();
-    file_system.parse_input(input);
+pub fn part_two(file_system: &FileSystem) -> Option<u32> {
     let used_disk_space = FileSystem::get_dir_size(file_system.root.as_ref());
-    println!("{}", used_disk_space);
+    advent_of_code::aoc_debug!("used disk space: {}", used_disk_space);
     let free_space = 70000000 - used_disk_space;
 
     let smallest_valid_size = file_system
@@ -151,10 +159,14 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(smallest_valid_size as u32)
 }
 
+advent_of_code::solution!(7, parse => part_one, part_two);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::read_input(&Day);
+    let file_system = &advent_of_code::parse!(parse, input);
+    advent_of_code::solve!(1, part_one, file_system);
+    advent_of_code::solve!(2, part_two, file_system);
+    advent_of_code::exit_on_failure();
 }
 
 #[cfg(test)]
@@ -162,14 +174,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), None);
+    fn test_examples() {
+        advent_of_code::check_examples(&Day);
     }
 }
@@ -1,4 +1,5 @@
-use std::cmp::Ordering;
+use advent_of_code::helpers::{span, ParseError};
+use std::{cmp::Ordering, num::ParseIntError};
 
 #[derive(Debug, Default, Clone, Copy)]
 struct Position {
@@ -76,25 +77,11 @@ impl PartialEq for Position {
     }
 }
 
-impl Eq for Position {
-    fn assert_receiver_is_total_eq(&self) {}
-}
+impl Eq for Position {}
 
 impl PartialOrd for Position {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self.x > other.x {
-            Some(Ordering::Greater)
-        } else if self.x == other.x {
-            if self.y > other.y {
-                Some(Ordering::Greater)
-            } else if self.y == other.y {
-                Some(Ordering::Equal)
-            } else {
-                Some(Ordering::Less)
-            }
-        } else {
-            Some(Ordering::Less)
-        }
+        Some(self.cmp(other))
     }
 }
 impl Ord for Position {
@@ -115,12 +102,22 @@ impl Ord for Position {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 struct Knot {
     positions: Vec<Position>,
     current: Position,
 }
 
+impl Default for Knot {
+    fn default() -> Self {
+        // the starting position counts as visited.
+        Knot {
+            positions: vec![Position::default()],
+            current: Position::default(),
+        }
+    }
+}
+
 impl Knot {
     fn move_head_next(&mut self, dir: &str) {
         let next = self.current.move_head(dir);
@@ -129,13 +126,9 @@ impl Knot {
     }
 
     fn move_tail_next(&mut self, rhs: &Position) {
-        let next = self.current.get_next_position(rhs);
-        match next {
-            Some(n) => {
-                self.positions.push(n);
-                self.current = n;
-            }
-            None => (),
+        if let Some(next) = self.current.get_next_position(rhs) {
+            self.positions.push(next);
+            self.current = next;
         }
     }
 
@@ -214,7 +207,7 @@ impl LinkedList {
     fn new(len: usize) -> Self {
         Self {
             segments: vec![(0, 0); len],
-            visited: Vec::new(),
+            visited: vec![(0, 0)],
         }
     }
 
@@ -251,66 +244,103 @@ impl LinkedList {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut grid = Grid::default();
-    let lines = input.lines();
+/// Parses the moves of the head, e.g. `R 4`, into their direction and distance.
+fn parse_moves(input: &str) -> Result<Vec<(&str, usize)>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let error = |message: &str| ParseError {
+                line: index + 1,
+                content: line.to_string(),
+                message: message.to_string(),
+            };
+
+            let (direction, magnitude) = line
+                .split_once(' ')
+                .ok_or_else(|| error("expected a direction and a distance"))?;
+            if !matches!(direction, "U" | "D" | "L" | "R") {
+                return Err(error("unknown direction"));
+            }
+            let magnitude = magnitude
+                .parse()
+                .map_err(|e: ParseIntError| error(&e.to_string()))?;
+            Ok((direction, magnitude))
+        })
+        .collect()
+}
 
-    for line in lines {
-        let (direction, magnitude) = line.split_once(" ").unwrap();
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    let mut grid = Grid::default();
+    let moves = parse_moves(input)?;
 
-        for _ in 0..magnitude.parse::<usize>().unwrap() {
-            grid.head.move_head_next(direction);
-            grid.tail.move_tail_next(&grid.head.current);
+    span!("simulate", {
+        for (direction, magnitude) in moves {
+            for _ in 0..magnitude {
+                grid.head.move_head_next(direction);
+                grid.tail.move_tail_next(&grid.head.current);
+            }
         }
-    }
+    });
 
-    Some(grid.tail.get_num_unique_positions())
+    Ok(span!("count", grid.tail.get_num_unique_positions()))
 }
 
-pub fn part_one_redux(input: &str) -> Option<u32> {
+pub fn part_one_redux(input: &str) -> Result<u32, ParseError> {
     let mut linked_list = LinkedList::new(2);
 
-    for line in input.lines() {
-        let (direction, magnitude) = line.split_once(" ").unwrap();
+    for (direction, magnitude) in parse_moves(input)? {
         let dir = Direction::new(direction);
-        for _ in 0..magnitude.parse().unwrap() {
+        for _ in 0..magnitude {
             linked_list.make_move(&dir);
         }
     }
-    Some(linked_list.get_num_unique_positions())
+    Ok(linked_list.get_num_unique_positions())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
     let mut grid = MultiGrid::default();
-    let lines = input.lines();
-
-    for line in lines {
-        let (direction, magnitude) = line.split_once(" ").unwrap();
+    let moves = parse_moves(input)?;
 
-        grid.move_head(direction, magnitude.parse::<usize>().unwrap());
-    }
+    span!("simulate", {
+        for (direction, magnitude) in moves {
+            grid.move_head(direction, magnitude);
+        }
+    });
 
-    Some(grid.get_num_unique_positions())
+    Ok(span!("count", grid.get_num_unique_positions()))
 }
-pub fn part_two_redux(input: &str) -> Option<u32> {
+pub fn part_two_redux(input: &str) -> Result<u32, ParseError> {
     let mut linked_list = LinkedList::new(10);
 
-    for line in input.lines() {
-        let (direction, magnitude) = line.split_once(" ").unwrap();
+    for (direction, magnitude) in parse_moves(input)? {
         let dir = Direction::new(direction);
-        for _ in 0..magnitude.parse().unwrap() {
+        for _ in 0..magnitude {
             linked_list.make_move(&dir);
         }
     }
-    Some(linked_list.get_num_unique_positions())
+    Ok(linked_list.get_num_unique_positions())
 }
 
+advent_of_code::solution!(
+    9,
+    ["rope-structs" => part_one, "linked-list" => part_one_redux],
+    ["rope-structs" => part_two, "linked-list" => part_two_redux]
+);
+
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(1, part_one_redux, input);
-    advent_of_code::solve!(2, part_two, input);
-    advent_of_code::solve!(2, part_two_redux, input);
+    let input = &advent_of_code::read_input(&Day);
+    advent_of_code::solve!(
+        1,
+        ["rope-structs" => part_one, "linked-list" => part_one_redux],
+        input
+    );
+    advent_of_code::solve!(
+        2,
+        ["rope-structs" => part_two, "linked-list" => part_two_redux],
+        input
+    );
+    advent_of_code::exit_on_failure();
 }
 
 #[cfg(test)]
@@ -318,14 +348,15 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_one(&input), None);
+    fn test_examples() {
+        advent_of_code::check_examples(&Day);
     }
 
     #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_two(&input), None);
+    fn test_invalid_move() {
+        let error = part_one("R 4\nX 2\n").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.message, "unknown direction");
+        assert!(part_two_redux("R four").is_err());
     }
 }
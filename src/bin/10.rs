@@ -1,4 +1,6 @@
+use advent_of_code::helpers::ParseError;
 use itertools::Itertools;
+use std::num::ParseIntError;
 
 struct Machine {
     cycles: Vec<isize>,
@@ -7,22 +9,29 @@ struct Machine {
 
 impl Machine {
     fn new() -> Self {
-        let mut cycles = Vec::new();
-        cycles.push(1);
-        Machine { cycles, x: 1 }
+        Machine {
+            cycles: vec![1],
+            x: 1,
+        }
     }
 
-    fn process_instruction(&mut self, instr: &str) {
+    fn process_instruction(&mut self, instr: &str) -> Result<(), String> {
         let mut split = instr.split_whitespace();
-        match split.next().unwrap() {
-            "noop" => self.cycles.push(self.x),
-            "addx" => {
+        match split.next() {
+            Some("noop") => self.cycles.push(self.x),
+            Some("addx") => {
+                let value = split
+                    .next()
+                    .ok_or("addx needs a value")?
+                    .parse::<isize>()
+                    .map_err(|e: ParseIntError| e.to_string())?;
                 self.cycles.push(self.x);
                 self.cycles.push(self.x);
-                self.x += split.next().unwrap().parse::<isize>().unwrap();
+                self.x += value;
             }
-            _ => panic!("Unknown instruction!"),
+            _ => return Err("unknown instruction".into()),
         }
+        Ok(())
     }
 
     fn get_strength(&self, index: usize) -> isize {
@@ -60,26 +69,26 @@ impl Machine {
     }
 }
 
-pub fn part_one(input: &str) -> Option<isize> {
+pub fn solve_both(input: &str) -> Result<(isize, String), ParseError> {
     let mut machine = Machine::new();
-    for line in input.lines() {
-        machine.process_instruction(line);
+    for (index, line) in input.lines().enumerate() {
+        machine
+            .process_instruction(line)
+            .map_err(|message| ParseError {
+                line: index + 1,
+                content: line.to_string(),
+                message,
+            })?;
     }
-    Some(machine.get_strengths())
+    Ok((machine.get_strengths(), machine.render()))
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let mut machine = Machine::new();
-    for line in input.lines() {
-        machine.process_instruction(line);
-    }
-    Some(machine.render())
-}
+advent_of_code::solution!(10, both => solve_both);
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::read_input(&Day);
+    advent_of_code::solve!(both => solve_both, input);
+    advent_of_code::exit_on_failure();
 }
 
 #[cfg(test)]
@@ -87,14 +96,28 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_one(&input), None);
+    fn test_examples() {
+        advent_of_code::check_examples(&Day);
     }
 
     #[test]
     fn test_part_two() {
         let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), None);
+        assert_eq!(
+            solve_both(&input).unwrap().1,
+            "##..##..##..##..##..##..##..##..##..##..\n\
+             ###...###...###...###...###...###...###.\n\
+             ####....####....####....####....####....\n\
+             #####.....#####.....#####.....#####.....\n\
+             ######......######......######......####\n\
+             #######.......#######.......#######.....\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_instruction() {
+        let error = solve_both("noop\naddx x\n").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert_eq!(error.content, "addx x");
     }
 }
@@ -2,6 +2,7 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::data;
 use std::io::Write;
 use std::path::PathBuf;
 use std::{env::temp_dir, io, process::Command};
@@ -10,14 +11,23 @@ use std::{fs, process};
 struct Args {
     day: u8,
     year: Option<i16>,
+    profile: Option<String>,
+    /// Session cookie file of the profile's account, passed on to aoc-cli.
+    session_file: Option<String>,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
+    let parsed = Args {
         day: args.free_from_str()?,
         year: args.opt_value_from_str(["-y", "--year"])?,
-    })
+        profile: args
+            .opt_value_from_str("--profile")?
+            .or_else(data::default_profile),
+        session_file: args.opt_value_from_str("--session-file")?,
+    };
+    advent_of_code::finish_args(args)?;
+    Ok(parsed)
 }
 
 fn remove_file(path: &PathBuf) {
@@ -47,8 +57,7 @@ fn main() {
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let input_path = data::path(&data::inputs_folder(args.profile.as_deref()), args.day);
 
     // check if aoc binary exists and is callable.
     if Command::new("aoc").arg("-V").output().is_err() {
@@ -63,6 +72,11 @@ fn main() {
         cmd_args.push(year.to_string());
     }
 
+    if let Some(session_file) = args.session_file {
+        cmd_args.push("--session-file".into());
+        cmd_args.push(session_file);
+    }
+
     cmd_args.append(&mut vec![
         "--input-file".into(),
         tmp_file_path.to_string_lossy().to_string(),
@@ -91,10 +105,20 @@ fn main() {
         }
     }
 
+    if let Some(dir) = input_path.parent() {
+        #[allow(unused_must_use)]
+        {
+            fs::create_dir_all(dir);
+        }
+    }
+
     match fs::copy(&tmp_file_path, &input_path) {
         Ok(_) => {
             println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+            println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            );
             exit_with_status(0, &tmp_file_path);
         }
         Err(e) => {
@@ -0,0 +1,141 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::crypt::{self, Key};
+use advent_of_code::data;
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process,
+};
+
+struct Args {
+    /// Generate a key and write it to the key file before encrypting.
+    new_key: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        new_key: args.contains("--new-key"),
+    };
+    advent_of_code::finish_args(args)?;
+    Ok(parsed)
+}
+
+/// The key file from `$AOC_INPUT_KEY_FILE`, or the default one.
+fn key_file() -> PathBuf {
+    env::var_os(crypt::KEY_FILE_VAR)
+        .filter(|path| !path.is_empty())
+        .map_or_else(crypt::default_key_file, PathBuf::from)
+}
+
+fn write_new_key(path: &Path) -> std::io::Result<()> {
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?;
+    writeln!(file, "{}", Key::generate())
+}
+
+/// The plain inputs in `dir` and in the profile folders inside of it, e.g. `inputs/09.txt` and `inputs/alice/09.txt`.
+fn plain_inputs(dir: &Path) -> Vec<PathBuf> {
+    let mut inputs = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return inputs;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if path.is_dir() {
+            inputs.extend(plain_inputs(&path));
+        } else if name
+            .strip_suffix(".txt")
+            .is_some_and(|day| day.len() == 2 && day.bytes().all(|b| b.is_ascii_digit()))
+        {
+            inputs.push(path);
+        }
+    }
+    inputs.sort();
+    inputs
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if args.new_key {
+        let path = key_file();
+        match write_new_key(&path) {
+            Ok(_) => println!("Created key file \"{}\"", path.display()),
+            Err(e) => {
+                eprintln!("Failed to create key file \"{}\": {}", path.display(), e);
+                process::exit(1);
+            }
+        }
+    }
+
+    let key = match crypt::load_key() {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("Failed to load key: {}. Pass --new-key to create one.", e);
+            process::exit(1);
+        }
+    };
+
+    let dir = data::roots().remove(0).join("inputs");
+    let inputs = plain_inputs(&dir);
+    if inputs.is_empty() {
+        eprintln!("No inputs to encrypt in \"{}\".", dir.display());
+        process::exit(1);
+    }
+
+    let mut failed = false;
+    for path in inputs {
+        let encrypted_path = crypt::encrypted_path(&path);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Failed to read \"{}\": {}", path.display(), e);
+                failed = true;
+                continue;
+            }
+        };
+
+        // encrypting uses a random nonce, rewriting unchanged inputs would only add noise to the git history.
+        let unchanged = fs::read(&encrypted_path)
+            .ok()
+            .and_then(|content| crypt::decrypt(&key, &content).ok())
+            .is_some_and(|decrypted| decrypted == input);
+        if unchanged {
+            println!("Unchanged \"{}\"", encrypted_path.display());
+            continue;
+        }
+
+        match fs::write(&encrypted_path, crypt::encrypt(&key, &input)) {
+            Ok(_) => println!("Encrypted \"{}\"", encrypted_path.display()),
+            Err(e) => {
+                eprintln!("Failed to write \"{}\": {}", encrypted_path.display(), e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+
+    println!("---");
+    println!(
+        "🎄 Commit the .enc files. Keep the key out of git, e.g. in a CI secret named {}.",
+        crypt::KEY_VAR
+    );
+}
@@ -0,0 +1,190 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable with the key for encrypted inputs, as 64 hex digits.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+/// Environment variable with the path of a file that holds the key, used if `AOC_INPUT_KEY` is not set.
+pub const KEY_FILE_VAR: &str = "AOC_INPUT_KEY_FILE";
+
+/// Encrypted files start with this, followed by the nonce and the ciphertext.
+const MAGIC: &[u8] = b"aoc-enc1";
+
+#[cfg(feature = "encryption")]
+const NONCE_LEN: usize = 12;
+
+/// A key for encrypting and decrypting inputs with ChaCha20-Poly1305.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    /// Parses a key written as 64 hex digits.
+    pub fn parse(hex: &str) -> Result<Key, String> {
+        let hex = hex.trim();
+        let invalid = || "a key needs to be 64 hex digits".to_string();
+        // `from_str_radix` alone would also accept a sign, e.g. `+f`.
+        if hex.len() != 64 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+
+        let mut key = [0; 32];
+        for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).map_err(|_| invalid())?;
+            *byte = u8::from_str_radix(digits, 16).map_err(|_| invalid())?;
+        }
+        Ok(Key(key))
+    }
+
+    /// A new random key.
+    #[cfg(feature = "encryption")]
+    pub fn generate() -> Key {
+        use chacha20poly1305::aead::{KeyInit, OsRng};
+        Key(chacha20poly1305::ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+    }
+}
+
+// keeps keys out of panic messages and debug output.
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Key(..)")
+    }
+}
+
+/// The key file that is used if neither `AOC_INPUT_KEY` nor `AOC_INPUT_KEY_FILE` is set: `.aoc-key` in the root of the crate.
+pub fn default_key_file() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(".aoc-key")
+}
+
+/// Loads the key from `$AOC_INPUT_KEY`, the file at `$AOC_INPUT_KEY_FILE`, or the [`default_key_file`].
+pub fn load_key() -> Result<Key, String> {
+    if let Some(key) = env::var(KEY_VAR).ok().filter(|key| !key.is_empty()) {
+        return Key::parse(&key).map_err(|e| format!("{}: {}", KEY_VAR, e));
+    }
+
+    let path = env::var_os(KEY_FILE_VAR)
+        .filter(|path| !path.is_empty())
+        .map_or_else(default_key_file, PathBuf::from);
+    let content = fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "no key for encrypted inputs, set {} or put it in {}: {}",
+            KEY_VAR,
+            path.display(),
+            e
+        )
+    })?;
+    Key::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Where the encrypted version of the input at `path` is stored, e.g. `inputs/09.txt.enc` for `inputs/09.txt`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".enc");
+    PathBuf::from(path)
+}
+
+/// Encrypts `plaintext` with a random nonce, so encrypting the same input twice gives different files.
+#[cfg(feature = "encryption")]
+pub fn encrypt(key: &Key, plaintext: &str) -> Vec<u8> {
+    use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+    use chacha20poly1305::ChaCha20Poly1305;
+
+    let cipher = ChaCha20Poly1305::new(&key.0.into());
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .expect("inputs are small enough to encrypt");
+
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+/// Decrypts the content of a file written by `encrypt`, which needs the `encryption` feature.
+/// Fails if the content was encrypted with another key or has been modified.
+pub fn decrypt(key: &Key, content: &[u8]) -> Result<String, String> {
+    let Some(content) = content.strip_prefix(MAGIC) else {
+        return Err("not an encrypted input".into());
+    };
+
+    #[cfg(feature = "encryption")]
+    {
+        use chacha20poly1305::aead::{Aead, KeyInit};
+        use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+
+        if content.len() < NONCE_LEN {
+            return Err("the file is truncated".into());
+        }
+        let (nonce, ciphertext) = content.split_at(NONCE_LEN);
+        let plaintext = ChaCha20Poly1305::new(&key.0.into())
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "wrong key, or the file has been modified".to_string())?;
+        String::from_utf8(plaintext).map_err(|_| "the decrypted input is not UTF-8".into())
+    }
+    #[cfg(not(feature = "encryption"))]
+    {
+        let _ = (key, content);
+        Err("reading encrypted inputs needs the `encryption` feature, e.g. `cargo run --release --features encryption`".into())
+    }
+}
+
+/// Decrypts an encrypted input file with the key from [`load_key`].
+pub fn decrypt_file(path: &Path) -> Result<String, String> {
+    let content = fs::read(path).map_err(|e| e.to_string())?;
+    decrypt(&load_key()?, &content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn test_parse_key() {
+        let key = Key::parse(&format!("{}\n", HEX)).unwrap();
+        assert_eq!(key.to_string(), HEX);
+        assert_eq!(format!("{:?}", key), "Key(..)");
+        assert!(Key::parse("0011").is_err());
+        assert!(Key::parse(&HEX.replace('a', "g")).is_err());
+        assert!(Key::parse(&HEX.replace("0f", "+f")).is_err());
+    }
+
+    #[test]
+    fn test_decrypt_plain_file() {
+        let key = Key::parse(HEX).unwrap();
+        assert_eq!(
+            decrypt(&key, b"R 4\nU 4\n"),
+            Err("not an encrypted input".into())
+        );
+    }
+
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn test_encrypt() {
+        let key = Key::parse(HEX).unwrap();
+        let encrypted = encrypt(&key, "R 4\nU 4\n");
+        assert_ne!(encrypted, encrypt(&key, "R 4\nU 4\n"));
+        assert_eq!(decrypt(&key, &encrypted), Ok("R 4\nU 4\n".into()));
+
+        let other = Key::generate();
+        assert_eq!(
+            decrypt(&other, &encrypted),
+            Err("wrong key, or the file has been modified".into())
+        );
+
+        let mut modified = encrypted.clone();
+        *modified.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&key, &modified).is_err());
+        assert!(decrypt(&key, &encrypted[..MAGIC.len() + 4]).is_err());
+    }
+}
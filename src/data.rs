@@ -0,0 +1,542 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::crypt;
+
+/// Environment variable with a directory that is searched for `inputs` and `examples` before `src`.
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// Environment variable with the profile whose inputs are used by default, see [`inputs_folder`].
+pub const PROFILE_VAR: &str = "AOC_PROFILE";
+
+/// The directories that contain the `inputs` and `examples` folders, in the order they are searched:
+/// `$AOC_DATA_DIR` if set, `src` of this crate, and `src` of the working directory for binaries that were moved.
+pub fn roots() -> Vec<PathBuf> {
+    let mut roots = Vec::new();
+    if let Some(dir) = env::var_os(DATA_DIR_VAR).filter(|dir| !dir.is_empty()) {
+        roots.push(PathBuf::from(dir));
+    }
+    roots.push(Path::new(env!("CARGO_MANIFEST_DIR")).join("src"));
+    if let Ok(cwd) = env::current_dir() {
+        let src = cwd.join("src");
+        if !roots.contains(&src) {
+            roots.push(src);
+        }
+    }
+    roots
+}
+
+/// Where the file of `day` in `folder` belongs, in the first of the [`roots`]. Used to create new files.
+pub fn path(folder: &str, day: u8) -> PathBuf {
+    roots().remove(0).join(folder).join(file_name(day))
+}
+
+/// The profile selected with the `AOC_PROFILE` environment variable, if any.
+pub fn default_profile() -> Option<String> {
+    env::var(PROFILE_VAR)
+        .ok()
+        .filter(|profile| !profile.is_empty())
+}
+
+/// The folder with the inputs of `profile`: `inputs/<profile>`, or `inputs` without a profile.
+pub fn inputs_folder(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("inputs/{}", profile),
+        None => "inputs".into(),
+    }
+}
+
+/// The names of all profiles, i.e. the folders in `inputs` of any of the [`roots`], sorted.
+pub fn profiles() -> Vec<String> {
+    let mut profiles: Vec<String> = roots()
+        .iter()
+        .filter_map(|root| fs::read_dir(root.join("inputs")).ok())
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            entry.file_type().ok()?.is_dir().then_some(())?;
+            entry.file_name().into_string().ok()
+        })
+        .collect();
+    profiles.sort();
+    profiles.dedup();
+    profiles
+}
+
+/// The answers recorded for the inputs of a profile, by day and part.
+pub type Answers = BTreeMap<(u8, u8), String>;
+
+/// Reads the answers recorded in `inputs/<profile>/answers.txt`, from the first of the [`roots`] that has it.
+/// Each line holds the day, the part and the answer, e.g. `9 2: 2622`. Line breaks in answers are written as `\n`.
+/// Blank lines and lines starting with `#` are skipped. A missing file means that no answers are recorded.
+pub fn answers(profile: &str) -> Result<Answers, String> {
+    for root in roots() {
+        let path = root.join("inputs").join(profile).join("answers.txt");
+        if let Ok(content) = fs::read_to_string(&path) {
+            return parse_answers(&content).map_err(|e| format!("{}: {}", path.display(), e));
+        }
+    }
+    Ok(Answers::new())
+}
+
+fn parse_answers(content: &str) -> Result<Answers, String> {
+    let mut answers = Answers::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let parsed = line.split_once(':').and_then(|(key, answer)| {
+            let (day, part) = key.trim().split_once(' ')?;
+            let day = day.parse().ok()?;
+            let part = part
+                .trim()
+                .parse()
+                .ok()
+                .filter(|part| matches!(part, 1 | 2))?;
+            Some(((day, part), answer.trim().replace("\\n", "\n")))
+        });
+        match parsed {
+            Some((key, answer)) => answers.insert(key, answer),
+            None => {
+                return Err(format!(
+                    "line {}: invalid answer \"{}\", expected e.g. \"9 2: 2622\"",
+                    index + 1,
+                    line
+                ))
+            }
+        };
+    }
+    Ok(answers)
+}
+
+fn file_name(day: u8) -> String {
+    format!("{:02}.txt", day)
+}
+
+/// A file that could not be read from any of the [`roots`].
+#[derive(Debug)]
+pub struct NotFound {
+    /// Every path that was tried, with the reason it could not be read.
+    pub tried: Vec<(PathBuf, io::Error)>,
+}
+
+impl fmt::Display for NotFound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not open input file, tried:")?;
+        for (path, error) in &self.tried {
+            write!(f, "\n  {}: {}", path.display(), error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for NotFound {}
+
+/// Why a file could not be read by [`read`].
+#[derive(Debug)]
+pub enum ReadError {
+    NotFound(NotFound),
+    /// An encrypted file was found, but could not be decrypted.
+    Decrypt {
+        path: PathBuf,
+        message: String,
+    },
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::NotFound(not_found) => not_found.fmt(f),
+            ReadError::Decrypt { path, message } => {
+                write!(f, "could not decrypt {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+/// Reads the file of `day` in `folder` from the first of the [`roots`] that has it, and cleans it up with `normalize`.
+/// If a root only has an encrypted `<day>.txt.enc`, it is decrypted with the key from [`crypt::load_key`].
+/// The answers declared at the top of files in `examples` are left out, see [`Example`]. Other files are read as they are.
+pub fn read(folder: &str, day: u8, normalize: Normalize) -> Result<String, ReadError> {
+    read_from(&roots(), folder, day).map(|content| match folder {
+        "examples" => normalize.apply(strip_header(&content)),
+        _ => normalize.apply(&content),
+    })
+}
+
+/// Where a day binary reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/<day>.txt`, or `inputs/<profile>/<day>.txt` of the given profile.
+    Inputs(Option<String>),
+    /// A file passed with `--input <path>`.
+    File(PathBuf),
+    /// Standard input, selected with `--input -`.
+    Stdin,
+    /// An example selected with `--example [N]`: `examples/<day>-<N>.txt`, or the first example of the day.
+    Example(Option<String>),
+}
+
+/// Reads the input of `day` from `source` and cleans it up with `normalize`.
+pub fn read_source(source: &InputSource, day: u8, normalize: Normalize) -> Result<String, String> {
+    let content = match source {
+        InputSource::Inputs(profile) => {
+            return read(&inputs_folder(profile.as_deref()), day, normalize)
+                .map_err(|e| e.to_string())
+        }
+        InputSource::File(path) => fs::read_to_string(path)
+            .map_err(|e| format!("could not read {}: {}", path.display(), e))?,
+        InputSource::Stdin => {
+            io::read_to_string(io::stdin()).map_err(|e| format!("could not read stdin: {}", e))?
+        }
+        InputSource::Example(name) => {
+            let examples = examples(day)?;
+            let example = match name {
+                Some(name) => {
+                    let file_name = format!("{:02}-{}.txt", day, name);
+                    examples.iter().find(|example| example.name == file_name)
+                }
+                None => examples.first(),
+            };
+            return match example {
+                Some(example) => Ok(normalize.apply(&example.input)),
+                None => Err(format!(
+                    "no example \"{}\" for day {}, found: {}",
+                    name.as_deref().unwrap_or_default(),
+                    day,
+                    examples
+                        .iter()
+                        .map(|example| example.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            };
+        }
+    };
+    Ok(normalize.apply(&content))
+}
+
+/// How input files are cleaned up before a day sees them. Everything is enabled by default.
+/// Days where whitespace is meaningful can opt out when registering with `solution!`, e.g.
+/// `solution!(5, part_one, part_two, normalize = Normalize { trailing_whitespace: false, ..Normalize::ALL })`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalize {
+    /// Removes a byte order mark at the start, which some Windows editors add.
+    pub bom: bool,
+    /// Turns `\r\n` line endings into `\n`.
+    pub line_endings: bool,
+    /// Removes whitespace at the end of every line and blank lines at the end of the input,
+    /// including the final newline. Also turns `\r\n` line endings into `\n`. Leading whitespace is always kept.
+    pub trailing_whitespace: bool,
+}
+
+impl Normalize {
+    pub const ALL: Normalize = Normalize {
+        bom: true,
+        line_endings: true,
+        trailing_whitespace: true,
+    };
+
+    /// Leaves input files as they are.
+    pub const NONE: Normalize = Normalize {
+        bom: false,
+        line_endings: false,
+        trailing_whitespace: false,
+    };
+
+    pub fn apply(&self, input: &str) -> String {
+        let input = match self.bom {
+            true => input.strip_prefix('\u{feff}').unwrap_or(input),
+            false => input,
+        };
+
+        if self.trailing_whitespace {
+            let mut normalized = input
+                .lines()
+                .map(str::trim_end)
+                .collect::<Vec<_>>()
+                .join("\n");
+            normalized.truncate(normalized.trim_end().len());
+            normalized
+        } else if self.line_endings {
+            input.replace("\r\n", "\n")
+        } else {
+            input.to_string()
+        }
+    }
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Normalize::ALL
+    }
+}
+
+/// Marks the header lines of an example file that declare the expected answer to a part, e.g. `#! part 1: 13`.
+const HEADER_PREFIX: &str = "#!";
+
+/// An example from `examples/<day>.txt` or `examples/<day>-<name>.txt`, e.g. `09-2.txt`.
+/// Example files can start with lines declaring the expected answers, which [`crate::check_examples`] checks:
+///
+/// ```text
+/// #! part 1: 13
+/// #! part 2: 1
+/// R 4
+/// U 4
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The file name, e.g. `09-2.txt`.
+    pub name: String,
+    /// The content after the header.
+    pub input: String,
+    /// The answers declared in the header, by part.
+    pub answers: Vec<(u8, String)>,
+}
+
+impl Example {
+    fn parse(name: &str, content: &str) -> Result<Example, String> {
+        let input = strip_header(content);
+        let answers = content[..content.len() - input.len()]
+            .lines()
+            .map(|line| {
+                parse_answer(line).ok_or_else(|| {
+                    format!(
+                        "{}: invalid header line \"{}\", expected e.g. \"{} part 1: 13\"",
+                        name, line, HEADER_PREFIX
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Example {
+            name: name.to_string(),
+            input: input.to_string(),
+            answers,
+        })
+    }
+}
+
+fn parse_answer(line: &str) -> Option<(u8, String)> {
+    let (part, answer) = line
+        .strip_prefix(HEADER_PREFIX)?
+        .trim_start()
+        .strip_prefix("part ")?
+        .split_once(':')?;
+    let part = part
+        .trim()
+        .parse()
+        .ok()
+        .filter(|part| matches!(part, 1 | 2))?;
+    Some((part, answer.trim().to_string()))
+}
+
+fn strip_header(content: &str) -> &str {
+    let mut rest = content;
+    while rest.starts_with(HEADER_PREFIX) {
+        rest = rest.split_once('\n').map_or("", |(_, rest)| rest);
+    }
+    rest
+}
+
+/// Reads all examples of `day`, from the first of the [`roots`] that has any, sorted by file name.
+pub fn examples(day: u8) -> Result<Vec<Example>, String> {
+    for root in roots() {
+        let examples = examples_in(&root.join("examples"), day)?;
+        if !examples.is_empty() {
+            return Ok(examples);
+        }
+    }
+    Err(format!(
+        "no examples for day {} in any of: {}",
+        day,
+        roots()
+            .iter()
+            .map(|root| root.join("examples").display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
+
+fn examples_in(dir: &Path, day: u8) -> Result<Vec<Example>, String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(Vec::new());
+    };
+
+    let prefix = format!("{:02}", day);
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| {
+            name.strip_prefix(&prefix)
+                .and_then(|rest| rest.strip_suffix(".txt"))
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+        })
+        .collect();
+    names.sort();
+
+    names
+        .iter()
+        .map(|name| {
+            let content = fs::read_to_string(dir.join(name))
+                .map_err(|e| format!("could not read {}: {}", dir.join(name).display(), e))?;
+            Example::parse(name, &content)
+        })
+        .collect()
+}
+
+fn read_from(roots: &[PathBuf], folder: &str, day: u8) -> Result<String, ReadError> {
+    let mut tried = Vec::new();
+    for root in roots {
+        let path = root.join(folder).join(file_name(day));
+        match fs::read_to_string(&path) {
+            Ok(content) => return Ok(content),
+            Err(e) => tried.push((path.clone(), e)),
+        }
+
+        let encrypted = crypt::encrypted_path(&path);
+        match fs::metadata(&encrypted) {
+            Ok(metadata) if metadata.is_file() => {
+                return crypt::decrypt_file(&encrypted).map_err(|message| ReadError::Decrypt {
+                    path: encrypted,
+                    message,
+                })
+            }
+            Ok(_) => tried.push((
+                encrypted,
+                io::Error::new(io::ErrorKind::InvalidInput, "not a file"),
+            )),
+            Err(e) => tried.push((encrypted, e)),
+        }
+    }
+    Err(ReadError::NotFound(NotFound { tried }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_from() {
+        let root = env::temp_dir().join(format!("aoc-data-{}", std::process::id()));
+        let missing = root.join("missing");
+        fs::create_dir_all(root.join("inputs")).unwrap();
+        fs::write(root.join("inputs").join("07.txt"), "$ cd /\n").unwrap();
+
+        let roots = [missing.clone(), root.clone()];
+        assert_eq!(read_from(&roots, "inputs", 7).unwrap(), "$ cd /\n");
+
+        let Err(ReadError::NotFound(error)) = read_from(&roots, "inputs", 8) else {
+            panic!("expected the file to be missing");
+        };
+        let tried: Vec<_> = error.tried.iter().map(|(path, _)| path.clone()).collect();
+        assert_eq!(
+            tried,
+            [
+                missing.join("inputs").join("08.txt"),
+                missing.join("inputs").join("08.txt.enc"),
+                root.join("inputs").join("08.txt"),
+                root.join("inputs").join("08.txt.enc")
+            ]
+        );
+        assert!(error
+            .to_string()
+            .starts_with("could not open input file, tried:\n  "));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_normalize() {
+        let input = "\u{feff}  1 \r\n\r\n2\t\r\n\r\n";
+        assert_eq!(Normalize::ALL.apply(input), "  1\n\n2");
+        assert_eq!(Normalize::NONE.apply(input), input);
+        assert_eq!(
+            Normalize {
+                line_endings: true,
+                ..Normalize::NONE
+            }
+            .apply(input),
+            "\u{feff}  1 \n\n2\t\n\n"
+        );
+        assert_eq!(
+            Normalize {
+                bom: true,
+                ..Normalize::NONE
+            }
+            .apply("\u{feff}1\n"),
+            "1\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers("# day part: answer\n9 1: 6190\n\n10 2: ##.\\n.##\n").unwrap();
+        assert_eq!(
+            answers.into_iter().collect::<Vec<_>>(),
+            [
+                ((9, 1), "6190".to_string()),
+                ((10, 2), "##.\n.##".to_string())
+            ]
+        );
+
+        assert_eq!(
+            parse_answers("9 1: 6190\n9: 2622\n"),
+            Err("line 2: invalid answer \"9: 2622\", expected e.g. \"9 2: 2622\"".into())
+        );
+    }
+
+    #[test]
+    fn test_example() {
+        let example =
+            Example::parse("09-2.txt", "#! part 2: 36\n#!part 1:  7 \nR 5\nU 8\n").unwrap();
+        assert_eq!(example.input, "R 5\nU 8\n");
+        assert_eq!(
+            example.answers,
+            [(2, "36".to_string()), (1, "7".to_string())]
+        );
+
+        let example = Example::parse("09.txt", "R 5\n").unwrap();
+        assert_eq!(example.input, "R 5\n");
+        assert!(example.answers.is_empty());
+
+        assert_eq!(
+            Example::parse("09.txt", "#! part 3: 1\nR 5\n"),
+            Err(
+                "09.txt: invalid header line \"#! part 3: 1\", expected e.g. \"#! part 1: 13\""
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_examples_in() {
+        let dir = env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["09-2.txt", "09-1.txt", "09.txt", "10.txt", "09.md"] {
+            fs::write(dir.join(name), "#! part 1: 13\nR 4\n").unwrap();
+        }
+
+        let names: Vec<String> = examples_in(&dir, 9)
+            .unwrap()
+            .into_iter()
+            .map(|example| example.name)
+            .collect();
+        assert_eq!(names, ["09-1.txt", "09-2.txt", "09.txt"]);
+        assert_eq!(examples_in(&dir.join("missing"), 9), Ok(Vec::new()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -2,3 +2,87 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+use std::{error::Error, fmt};
+
+pub use crate::span;
+
+/// A line of the input that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    pub content: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {} ({:?})",
+            self.line, self.message, self.content
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every non-empty line of `input`, e.g. `parse_lines(input, str::parse::<u32>)`.
+/// Fails on the first line that `parse` rejects, with its line number.
+pub fn parse_lines<T, E: fmt::Display>(
+    input: &str,
+    parse: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<T>, ParseError> {
+    let blocks = parse_blocks(input, parse)?;
+    Ok(blocks.into_iter().flatten().collect())
+}
+
+/// Parses the lines of blocks separated by blank lines, e.g. the calories carried by each elf.
+/// Fails on the first line that `parse` rejects, with its line number.
+pub fn parse_blocks<T, E: fmt::Display>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut blocks = vec![Vec::new()];
+
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            blocks.push(Vec::new());
+            continue;
+        }
+
+        let value = parse(line).map_err(|error| ParseError {
+            line: index + 1,
+            content: line.to_string(),
+            message: error.to_string(),
+        })?;
+        blocks.last_mut().unwrap().push(value);
+    }
+
+    blocks.retain(|block| !block.is_empty());
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_blocks() {
+        assert_eq!(
+            parse_blocks("1\n2\n\n3\n", str::parse::<u32>),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+        assert_eq!(
+            parse_lines("1\n2\n\n3", str::parse::<u32>),
+            Ok(vec![1, 2, 3])
+        );
+
+        let error = parse_lines("1\n\n2x", str::parse::<u32>).unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(
+            error.to_string(),
+            "line 3: invalid digit found in string (\"2x\")"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+const HEADER: &str = "commit,date,day,part,elapsed_ns";
+
+/// The timing of a part in a recorded run of the `all` runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub commit: String,
+    /// UTC timestamp of the run, e.g. `2022-12-09T18:30:00Z`.
+    pub date: String,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed: Duration,
+}
+
+/// Location of the history file, `benchmarks/history.csv` in the repository,
+/// or `benchmarks/<profile>/history.csv` for the inputs of a profile, as their timings cannot be compared.
+pub fn path(profile: Option<&str>) -> PathBuf {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("benchmarks");
+    match profile {
+        Some(profile) => dir.join(profile).join("history.csv"),
+        None => dir.join("history.csv"),
+    }
+}
+
+/// Reads all entries, oldest first. A missing file is an empty history.
+pub fn load(path: &Path) -> io::Result<Vec<Entry>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && *line != HEADER)
+        .map(|(index, line)| {
+            parse_entry(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: malformed entry", path.display(), index + 1),
+                )
+            })
+        })
+        .collect()
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let mut fields = line.split(',');
+    let entry = Entry {
+        commit: fields.next()?.to_string(),
+        date: fields.next()?.to_string(),
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        elapsed: Duration::from_nanos(fields.next()?.parse().ok()?),
+    };
+    fields.next().is_none().then_some(entry)
+}
+
+/// Appends `entries` to the history file, creating it if needed.
+pub fn append(path: &Path, entries: &[Entry]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().append(true).create(true).open(path)?;
+
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for entry in entries {
+        writeln!(
+            file,
+            "{},{},{},{},{}",
+            entry.commit,
+            entry.date,
+            entry.day,
+            entry.part,
+            entry.elapsed.as_nanos()
+        )?;
+    }
+    Ok(())
+}
+
+/// A part that got slower than allowed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    /// The recorded entry the part was compared to.
+    pub previous: Entry,
+    pub elapsed: Duration,
+    /// Relative change in percent, e.g. `25.0` for a part that takes a quarter longer.
+    pub change: f64,
+}
+
+/// Compares `current` timings to the latest recorded entry of each part, or to the latest one at the
+/// `baseline` commit. Returns the parts that got more than `threshold` percent slower.
+pub fn compare(
+    history: &[Entry],
+    current: &[Entry],
+    baseline: Option<&str>,
+    threshold: f64,
+) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|entry| {
+            let previous = history.iter().rev().find(|previous| {
+                previous.day == entry.day
+                    && previous.part == entry.part
+                    && baseline.is_none_or(|baseline| previous.commit.starts_with(baseline))
+            })?;
+
+            let change =
+                (entry.elapsed.as_secs_f64() / previous.elapsed.as_secs_f64() - 1.0) * 100.0;
+
+            (change > threshold).then(|| Regression {
+                previous: previous.clone(),
+                elapsed: entry.elapsed,
+                change,
+            })
+        })
+        .collect()
+}
+
+/// Parses a percentage like `10` or `2.5`.
+pub fn parse_threshold(s: &str) -> Result<f64, String> {
+    match s.trim_end_matches('%').parse::<f64>() {
+        Ok(threshold) if threshold.is_finite() && threshold >= 0.0 => Ok(threshold),
+        _ => Err(format!("\"{}\" is not a valid percentage", s)),
+    }
+}
+
+/// The checked out commit, with a `-dirty` suffix if there are uncommitted changes.
+/// Changes to the history itself do not count, it is written by every run.
+/// Returns `"unknown"` outside of a git repository.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(commit) => match git(&["status", "--porcelain", "--", ".", ":!benchmarks"]) {
+            Some(changes) if !changes.is_empty() => format!("{}-dirty", commit),
+            _ => commit,
+        },
+        None => "unknown".into(),
+    }
+}
+
+/// The current time as a UTC timestamp.
+pub fn current_date() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    format_date(secs)
+}
+
+/// Formats seconds since the unix epoch as `YYYY-MM-DDTHH:MM:SSZ`.
+fn format_date(secs: u64) -> String {
+    let days = (secs / 86400) as i64;
+    let secs = secs % 86400;
+
+    // converts days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(commit: &str, day: u8, part: u8, micros: u64) -> Entry {
+        Entry {
+            commit: commit.into(),
+            date: "2022-12-09T18:30:00Z".into(),
+            day,
+            part,
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_date(1670610600), "2022-12-09T18:30:00Z");
+        assert_eq!(format_date(1709208000), "2024-02-29T12:00:00Z");
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        assert_eq!(
+            parse_entry("a1b2c3d,2022-12-09T18:30:00Z,9,1,5000"),
+            Some(entry("a1b2c3d", 9, 1, 5))
+        );
+        assert_eq!(parse_entry("a1b2c3d,2022-12-09T18:30:00Z,9,1"), None);
+        assert_eq!(parse_entry("a1b2c3d,2022-12-09T18:30:00Z,9,1,5,0"), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = [
+            entry("aaaaaaa", 9, 1, 100),
+            entry("bbbbbbb", 9, 1, 200),
+            entry("bbbbbbb", 9, 2, 100),
+        ];
+        let current = [
+            entry("ccccccc", 9, 1, 250),
+            entry("ccccccc", 9, 2, 105),
+            entry("ccccccc", 10, 1, 1),
+        ];
+
+        let regressions = compare(&history, &current, None, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].previous, history[1]);
+        assert_eq!(regressions[0].change, 25.0);
+
+        let regressions = compare(&history, &current, Some("aaa"), 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].previous, history[0]);
+        assert_eq!(regressions[0].change, 150.0);
+    }
+}
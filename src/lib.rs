@@ -3,92 +3,670 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use std::any::Any;
 use std::env;
-use std::fs;
+use std::ffi::OsString;
+use std::fmt::Display;
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::Instant;
 
+pub mod bench;
+pub mod crypt;
+pub mod data;
 pub mod helpers;
+pub mod history;
+pub mod memory;
+pub mod readme;
+pub mod report;
+pub mod runner;
+pub mod spans;
+
+use bench::Bench;
+use data::{InputSource, Normalize};
+use report::{Outcome, PartResult, Reporter, Terminal};
+use runner::PartRun;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Runs a part of the current day and prints its result.
+/// Expects the `DAY` constant declared by [`solution!`] to be in scope.
+/// Named variants of a part can be passed as a list. They are cross-checked and their timings compared.
+/// A panicking part is reported and the next part still runs, see [`exit_on_failure`].
+/// Parts are run repeatedly when the binary is started with `--bench`, see [`day_args`].
+/// `$input` is either the input text or the value returned by [`parse!`].
+/// Days that solve both parts together use `solve!(both => solve_both, input)`.
 #[macro_export]
 macro_rules! solve {
+    (both => $solver:ident, $input:expr) => {{
+        let run = $crate::runner::run_isolated($crate::report::BOTH, || {
+            $crate::bench::measure($crate::day_args().bench, || {
+                $crate::run_both(DAY, $solver, $input)
+            })
+        });
+        $crate::print_run(DAY, &run);
+    }};
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
+        let run = $crate::runner::run_isolated($part, || {
+            $crate::bench::measure($crate::day_args().bench, || {
+                $crate::run_part(DAY, $part, $solver, $input)
+            })
+        });
+        $crate::print_run(DAY, &run);
+    }};
+    ($part:expr, [$($name:literal => $solver:ident),+ $(,)?], $input:expr) => {{
+        let variants = [$($crate::Variant {
+            name: $name,
+            solver: |input| $crate::run_part(DAY, $part, $solver, input),
+        }),+];
+        let run =
+            $crate::runner::run_variants($part, &variants, $input, $crate::day_args().bench);
+        $crate::print_run(DAY, &run);
+    }};
+}
+
+/// Runs the parse phase of the current day, prints its timing and returns the parsed input.
+/// Exits if parsing panics, as the parts cannot run without its result.
+#[macro_export]
+macro_rules! parse {
+    ($parse:ident, $input:expr) => {
+        $crate::parse_input(DAY, $parse, $input)
+    };
+}
+
+#[doc(hidden)]
+pub fn parse_input<P, E: Display>(day: u8, parse: impl Fn(&str) -> Result<P, E>, input: &str) -> P {
+    let (run, parsed) = runner::run_parse(day, parse, input, day_args().bench);
+    print_run(day, &run);
+    match parsed {
+        Some(parsed) => parsed,
+        None => process::exit(1),
+    }
+}
+
+/// Prints a debug message to stderr, formatted like `println!`.
+/// Only day binaries started with `--verbose` or with the `AOC_DEBUG` environment variable set print anything.
+/// Messages are dropped without formatting them with `--bench` and in the `all` runner, so they do not affect timings.
+#[macro_export]
+macro_rules! aoc_debug {
+    ($($arg:tt)*) => {
+        if $crate::debug_enabled() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+static DEBUG: AtomicBool = AtomicBool::new(false);
+
+#[doc(hidden)]
+pub fn debug_enabled() -> bool {
+    DEBUG.load(Ordering::Relaxed)
+}
+
+static FAILED: AtomicBool = AtomicBool::new(false);
+
+/// Prints a part run by [`solve!`] and remembers whether it failed.
+#[doc(hidden)]
+pub fn print_run(day: u8, run: &PartRun) {
+    Terminal.part(day, run.part, &run.outcome);
+    if !run.variants.is_empty() {
+        Terminal.variants(day, run.part, &run.variants);
+    }
+    if run.outcome.is_failure() {
+        FAILED.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Exits with a failure status if any part run by [`solve!`] failed, e.g. by panicking.
+/// Call it at the end of a day's `main`.
+pub fn exit_on_failure() {
+    if FAILED.load(Ordering::Relaxed) {
+        process::exit(1);
+    }
+}
+
+/// Command line arguments of a day binary.
+pub struct DayArgs {
+    /// Set by `--bench`, with `--iterations` and `--warmup`.
+    pub bench: Option<Bench>,
+    /// Set by `--spans`, prints the time spent in each [`span!`] of a part.
+    pub spans: bool,
+    /// Set by `--verbose` or the `AOC_DEBUG` environment variable, prints the messages of [`aoc_debug!`].
+    pub verbose: bool,
+    /// Set by `--input <path>`, `--input -` for stdin, `--example [N]`, or `--profile <name>`, see [`read_input`].
+    pub input: InputSource,
+}
+
+fn parse_day_args() -> Result<DayArgs, pico_args::Error> {
+    let mut raw: Vec<OsString> = env::args_os().skip(1).collect();
+    let example = take_example(&mut raw);
+    let mut args = pico_args::Arguments::from_vec(raw);
+
+    let spans = args.contains("--spans");
+    if spans && !cfg!(feature = "spans") {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause:
+                "--spans needs the spans feature, e.g. `cargo solve 09 --features spans -- --spans`"
+                    .into(),
+        });
+    }
+
+    let profile = args
+        .opt_value_from_str("--profile")?
+        .or_else(data::default_profile);
+
+    let input = match (args.opt_value_from_str::<_, String>("--input")?, example) {
+        (Some(_), Some(_)) => {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--input and --example cannot be combined".into(),
+            })
+        }
+        (Some(path), None) if path == "-" => InputSource::Stdin,
+        (Some(path), None) => InputSource::File(path.into()),
+        (None, Some(name)) => InputSource::Example(name),
+        (None, None) => InputSource::Inputs(profile),
+    };
+
+    let day_args = DayArgs {
+        bench: bench::parse_args(&mut args)?,
+        spans,
+        verbose: args.contains("--verbose")
+            || env::var_os("AOC_DEBUG").is_some_and(|value| !value.is_empty() && value != "0"),
+        input,
+    };
+    finish_args(args)?;
+    Ok(day_args)
+}
+
+/// Fails if `args` has arguments left that no option consumed, e.g. a misspelled flag like `--day 3`.
+pub fn finish_args(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    let unused = args.finish();
+    if unused.is_empty() {
+        return Ok(());
+    }
+    Err(pico_args::Error::ArgumentParsingFailed {
+        cause: format!(
+            "unknown arguments: {}",
+            unused
+                .iter()
+                .map(|arg| arg.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" ")
+        ),
+    })
+}
+
+/// Removes `--example [N]` from `args`. pico-args does not support options with an optional value.
+fn take_example(args: &mut Vec<OsString>) -> Option<Option<String>> {
+    let index = args.iter().position(|arg| arg == "--example")?;
+    args.remove(index);
+
+    let name = args
+        .get(index)
+        .and_then(|arg| arg.to_str())
+        .filter(|arg| !arg.starts_with('-'))
+        .map(String::from);
+    if name.is_some() {
+        args.remove(index);
+    }
+    Some(name)
+}
+
+/// Returns the arguments of the running day binary, parsing them on first use.
+pub fn day_args() -> &'static DayArgs {
+    static DAY_ARGS: OnceLock<DayArgs> = OnceLock::new();
+    DAY_ARGS.get_or_init(|| match parse_day_args() {
+        Ok(args) => {
+            if args.spans {
+                spans::enable();
+            }
+            DEBUG.store(args.verbose && args.bench.is_none(), Ordering::Relaxed);
+            args
+        }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    })
+}
+
+/// A day's solution as seen by the `all` runner.
+/// Implemented for each day binary by the [`solution!`] macro.
+pub trait Solution: Sync {
+    fn day(&self) -> u8;
+    fn part_one(&self, input: Input) -> Outcome;
+    fn part_two(&self, input: Input) -> Outcome;
+
+    /// The day's parse function, if it was registered with one. The parts then receive its result.
+    fn parser(&self) -> Option<Parser> {
+        None
+    }
+
+    /// Whether the day solves both parts together, in which case the runner calls [`Solution::solve_both`]
+    /// instead of the individual parts.
+    fn solves_both(&self) -> bool {
+        false
+    }
+
+    /// Solves both parts at once, see [`report::BOTH`].
+    fn solve_both(&self, _input: Input) -> Outcome {
+        Outcome::NotSolved
+    }
+
+    /// How the day's input is cleaned up before the parts see it.
+    fn normalize(&self) -> Normalize {
+        Normalize::default()
+    }
+
+    /// Alternative implementations of `part`, the first being the one `part_one` and `part_two` run.
+    /// Empty unless the day registered variants with [`solution!`].
+    fn variants(&self, _part: u8) -> Vec<Variant> {
+        Vec::new()
+    }
+}
+
+/// Parses the input of a day, for [`Solution::parser`]. Fails with the message of the parse error.
+pub type Parser = fn(&str) -> Result<Box<dyn Any>, String>;
+
+/// What the parts of a day receive: the input text, or the result of the day's parse function.
+#[derive(Clone, Copy)]
+pub enum Input<'a> {
+    Text(&'a str),
+    Parsed(&'a dyn Any),
+}
+
+impl<'a> Input<'a> {
+    pub fn text(self) -> &'a str {
+        match self {
+            Input::Text(text) => text,
+            Input::Parsed(_) => panic!("expected the input text, got parsed input"),
+        }
+    }
+
+    pub fn parsed<P: 'static>(self) -> &'a P {
+        match self {
+            Input::Parsed(parsed) => parsed
+                .downcast_ref()
+                .expect("parsed input has an unexpected type"),
+            Input::Text(_) => panic!("expected parsed input, got the input text"),
+        }
+    }
+}
+
+/// A named implementation of a part.
+#[derive(Debug, Clone, Copy)]
+pub struct Variant {
+    pub name: &'static str,
+    pub solver: fn(&str) -> Outcome,
+}
+
+/// Registers a day binary with the `all` runner.
+/// `build.rs` includes every `src/bin/NN.rs` in the runner, which expects the `Day` type declared here.
+///
+/// Parts with several implementations can be registered as lists of named variants, e.g.
+/// `solution!(9, ["structs" => part_one, "linked-list" => part_one_redux], ["structs" => part_two])`.
+///
+/// Days that parse their input once for both parts register the parse function, e.g.
+/// `solution!(7, parse => part_one, part_two)`. It returns a `Result` whose error fails the day,
+/// and the parts take a reference to the parsed value.
+///
+/// Days that compute both answers in one go register a function returning both, e.g.
+/// `solution!(10, both => solve_both)`.
+///
+/// Days where whitespace in the input is meaningful can change how it is cleaned up, e.g.
+/// `solution!(5, part_one, part_two, normalize = Normalize::NONE)`, see [`data::Normalize`].
+/// Read the input with [`read_file_with`] in the day's `main` then.
+#[macro_export]
+macro_rules! solution {
+    ($day:expr $(, normalize = $normalize:expr)?) => {
+        $crate::solution!($day, part_one, part_two $(, normalize = $normalize)?);
+    };
+    ($day:expr, both => $solve_both:ident $(, normalize = $normalize:expr)?) => {
+        pub const DAY: u8 = $day;
+
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            fn day(&self) -> u8 {
+                DAY
+            }
+
+            $(
+                fn normalize(&self) -> $crate::data::Normalize {
+                    $normalize
                 }
-                None => {
-                    println!("not solved.")
+            )?
+
+            fn solves_both(&self) -> bool {
+                true
+            }
+
+            fn solve_both(&self, input: $crate::Input) -> $crate::report::Outcome {
+                $crate::run_both(DAY, $solve_both, input.text())
+            }
+
+            fn part_one(&self, input: $crate::Input) -> $crate::report::Outcome {
+                match self.solve_both(input) {
+                    $crate::report::Outcome::Solved(result) => {
+                        $crate::report::Outcome::Solved($crate::report::PartResult {
+                            part: 1,
+                            answer_two: None,
+                            ..result
+                        })
+                    }
+                    outcome => outcome,
+                }
+            }
+
+            fn part_two(&self, input: $crate::Input) -> $crate::report::Outcome {
+                match self.solve_both(input) {
+                    $crate::report::Outcome::Solved(result) => {
+                        $crate::report::Outcome::Solved($crate::report::PartResult {
+                            part: 2,
+                            answer: result.answer_two.unwrap_or_default(),
+                            answer_two: None,
+                            ..result
+                        })
+                    }
+                    outcome => outcome,
                 }
             }
         }
+    };
+    ($day:expr, $parse:ident => $part_one:ident, $part_two:ident $(, normalize = $normalize:expr)?) => {
+        pub const DAY: u8 = $day;
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            fn day(&self) -> u8 {
+                DAY
+            }
+
+            $(
+                fn normalize(&self) -> $crate::data::Normalize {
+                    $normalize
+                }
+            )?
+
+            fn parser(&self) -> Option<$crate::Parser> {
+                Some(|input| match $parse(input) {
+                    Ok(parsed) => Ok(Box::new(parsed)),
+                    Err(error) => Err(format!("{:#}", error)),
+                })
+            }
+
+            fn part_one(&self, input: $crate::Input) -> $crate::report::Outcome {
+                $crate::run_part(DAY, 1, $part_one, input.parsed())
+            }
+
+            fn part_two(&self, input: $crate::Input) -> $crate::report::Outcome {
+                $crate::run_part(DAY, 2, $part_two, input.parsed())
+            }
+        }
+    };
+    ($day:expr, $part_one:ident, $part_two:ident $(, normalize = $normalize:expr)?) => {
+        pub const DAY: u8 = $day;
+
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            fn day(&self) -> u8 {
+                DAY
+            }
+
+            $(
+                fn normalize(&self) -> $crate::data::Normalize {
+                    $normalize
+                }
+            )?
+
+            fn part_one(&self, input: $crate::Input) -> $crate::report::Outcome {
+                $crate::run_part(DAY, 1, $part_one, input.text())
+            }
+
+            fn part_two(&self, input: $crate::Input) -> $crate::report::Outcome {
+                $crate::run_part(DAY, 2, $part_two, input.text())
+            }
+        }
+    };
+    (
+        $day:expr,
+        [$($name_one:literal => $part_one:ident),+ $(,)?],
+        [$($name_two:literal => $part_two:ident),+ $(,)?]
+        $(, normalize = $normalize:expr)? $(,)?
+    ) => {
+        pub const DAY: u8 = $day;
+
+        pub struct Day;
+
+        impl $crate::Solution for Day {
+            fn day(&self) -> u8 {
+                DAY
+            }
+
+            $(
+                fn normalize(&self) -> $crate::data::Normalize {
+                    $normalize
+                }
+            )?
+
+            fn part_one(&self, input: $crate::Input) -> $crate::report::Outcome {
+                ($crate::Solution::variants(self, 1)[0].solver)(input.text())
+            }
+
+            fn part_two(&self, input: $crate::Input) -> $crate::report::Outcome {
+                ($crate::Solution::variants(self, 2)[0].solver)(input.text())
+            }
+
+            fn variants(&self, part: u8) -> Vec<$crate::Variant> {
+                match part {
+                    1 => vec![$($crate::Variant {
+                        name: $name_one,
+                        solver: |input| $crate::run_part(DAY, 1, $part_one, input),
+                    }),+],
+                    _ => vec![$($crate::Variant {
+                        name: $name_two,
+                        solver: |input| $crate::run_part(DAY, 2, $part_two, input),
+                    }),+],
+                }
+            }
+        }
+    };
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// The return types accepted for parts: `Option<T>`, where `None` means the part is not solved yet,
+/// and `Result<T, E>`, where an error means the part failed, e.g. on malformed input.
+pub trait Answer {
+    fn into_answer(self) -> Result<Option<String>, String>;
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+impl<T: Display> Answer for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+impl<T: Display, E: Display> Answer for Result<T, E> {
+    fn into_answer(self) -> Result<Option<String>, String> {
+        // the alternate form prints the whole chain for error types that support it, e.g. `anyhow::Error`.
+        self.map(|answer| Some(answer.to_string()))
+            .map_err(|error| format!("{:#}", error))
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Runs and times a single part.
+pub fn run_part<I: ?Sized, A: Answer>(
+    day: u8,
+    part: u8,
+    solver: impl FnOnce(&I) -> A,
+    input: &I,
+) -> Outcome {
+    let ((answer, elapsed), allocations) = memory::track(|| {
+        let timer = Instant::now();
+        let answer = solver(input);
+        (answer, timer.elapsed())
+    });
+    let spans = spans::take();
+
+    match answer.into_answer() {
+        Ok(Some(answer)) => Outcome::Solved(PartResult {
+            day,
+            part,
+            answer,
+            answer_two: None,
+            elapsed,
+            stats: None,
+            allocations,
+            spans,
+        }),
+        Ok(None) => Outcome::NotSolved,
+        Err(error) => Outcome::Failed(error),
+    }
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
+/// The return types accepted for `solve_both`: a tuple of both answers, or a `Result` of one,
+/// where an error means the day failed, e.g. on malformed input.
+pub trait BothAnswers {
+    fn into_answers(self) -> Result<(String, String), String>;
+}
+
+impl<A: Display, B: Display> BothAnswers for (A, B) {
+    fn into_answers(self) -> Result<(String, String), String> {
+        Ok((self.0.to_string(), self.1.to_string()))
+    }
+}
+
+impl<A: Display, B: Display, E: Display> BothAnswers for Result<(A, B), E> {
+    fn into_answers(self) -> Result<(String, String), String> {
+        self.map_err(|error| format!("{:#}", error))?.into_answers()
+    }
+}
+
+/// Runs and times a function that solves both parts of a day at once.
+pub fn run_both<I: ?Sized, A: BothAnswers>(
+    day: u8,
+    solver: impl FnOnce(&I) -> A,
+    input: &I,
+) -> Outcome {
+    let ((answers, elapsed), allocations) = memory::track(|| {
+        let timer = Instant::now();
+        let answers = solver(input);
+        (answers, timer.elapsed())
+    });
+    let spans = spans::take();
+
+    match answers.into_answers() {
+        Ok((answer, answer_two)) => Outcome::Solved(PartResult {
+            day,
+            part: report::BOTH,
+            answer,
+            answer_two: Some(answer_two),
+            elapsed,
+            stats: None,
+            allocations,
+            spans,
+        }),
+        Err(error) => Outcome::Failed(error),
+    }
+}
+
+/// Reads `<folder>/<day>.txt`, e.g. `read_file("inputs", 7)`. Panics with the paths that were tried if it is missing.
+/// See [`data::roots`] for where the file is looked for.
+/// The file is cleaned up with the default [`Normalize`] settings, see [`read_file_with`] for others.
+/// A day's `main` uses [`read_input`] instead, which also supports `--input` and `--example`.
+#[track_caller]
+pub fn read_file(folder: &str, day: u8) -> String {
+    read_file_with(folder, day, Normalize::default())
+}
+
+/// Like [`read_file`], for days that registered their own [`Normalize`] settings with [`solution!`].
+#[track_caller]
+pub fn read_file_with(folder: &str, day: u8, normalize: Normalize) -> String {
+    match data::read(folder, day, normalize) {
+        Ok(content) => content,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Reads the input of the running day binary: `inputs/<day>.txt`, or the file, stdin or example selected with
+/// `--input <path>`, `--input -` or `--example [N]`. `--profile <name>` or the `AOC_PROFILE` environment variable
+/// select `inputs/<name>/<day>.txt` instead. The input is cleaned up as registered with [`solution!`].
+/// Exits if the input cannot be read. Call it in a day's `main` with `advent_of_code::read_input(&Day)`.
+pub fn read_input(solution: &dyn Solution) -> String {
+    let source = &day_args().input;
+    match data::read_source(source, solution.day(), solution.normalize()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            process::exit(1);
         }
-    })
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Checks the answers declared in the headers of all example files of a day, e.g. `#! part 1: 13` at the top of
+/// `examples/09-1.txt`, see [`data::Example`]. Parts with named variants are checked for every variant.
+/// Call it from a day's tests with `advent_of_code::check_examples(&Day)`. Panics listing every wrong answer.
+#[track_caller]
+pub fn check_examples(solution: &dyn Solution) {
+    let examples = match data::examples(solution.day()) {
+        Ok(examples) => examples,
+        Err(e) => panic!("{}", e),
+    };
+
+    let mut failures = Vec::new();
+    for example in &examples {
+        let text = solution.normalize().apply(&example.input);
+        let parsed = match solution.parser().map(|parse| parse(&text)).transpose() {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                failures.push(format!("{}: could not parse: {}", example.name, error));
+                continue;
+            }
+        };
+        let input = match &parsed {
+            Some(parsed) => Input::Parsed(parsed.as_ref()),
+            None => Input::Text(&text),
+        };
+
+        for (part, expected) in &example.answers {
+            let mut outcomes = vec![(
+                String::new(),
+                match part {
+                    1 => solution.part_one(input),
+                    _ => solution.part_two(input),
+                },
+            )];
+            // the first variant is the one `part_one` and `part_two` run.
+            for variant in solution.variants(*part).iter().skip(1) {
+                outcomes.push((format!(" ({})", variant.name), (variant.solver)(&text)));
+            }
+
+            for (variant, outcome) in outcomes {
+                let answer = match &outcome {
+                    Outcome::Solved(result) if &result.answer == expected => continue,
+                    Outcome::Solved(result) => result.answer.clone(),
+                    outcome => outcome
+                        .error()
+                        .unwrap_or_else(|| outcome.status().replace('_', " ")),
+                };
+                failures.push(format!(
+                    "{} part {}{}: expected {}, got {}",
+                    example.name, part, variant, expected, answer
+                ));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "wrong answers to the examples of day {}:\n{}",
+        solution.day(),
+        failures.join("\n")
+    );
 }
 
 #[cfg(test)]
@@ -96,30 +674,70 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
+    fn test_take_example() {
+        let args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+
+        let mut raw = args(&["--example", "2", "--bench"]);
+        assert_eq!(take_example(&mut raw), Some(Some("2".into())));
+        assert_eq!(raw, args(&["--bench"]));
+
+        let mut raw = args(&["--example", "--bench"]);
+        assert_eq!(take_example(&mut raw), Some(None));
+        assert_eq!(raw, args(&["--bench"]));
+
+        let mut raw = args(&["--bench", "--example"]);
+        assert_eq!(take_example(&mut raw), Some(None));
+        assert_eq!(raw, args(&["--bench"]));
+
+        assert_eq!(take_example(&mut args(&["--input", "-"])), None);
+    }
+
+    #[test]
+    fn test_finish_args() {
+        let args = |args: &[&str]| {
+            pico_args::Arguments::from_vec(args.iter().map(OsString::from).collect())
+        };
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+        let mut parsed = args(&["--days", "1-3"]);
+        let _: Option<String> = parsed.opt_value_from_str("--days").unwrap();
+        assert!(finish_args(parsed).is_ok());
+
+        let mut parsed = args(&["--days", "1-3", "--dayz", "5"]);
+        let _: Option<String> = parsed.opt_value_from_str("--days").unwrap();
+        assert_eq!(
+            finish_args(parsed).unwrap_err().to_string(),
+            "failed to parse a binary argument: unknown arguments: --dayz 5"
         );
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+    #[test]
+    fn test_run_part() {
+        let outcome = run_part(3, 2, |input| input.parse::<u32>().ok(), "42");
+        let result = outcome.result().unwrap();
+        assert_eq!((result.day, result.part), (3, 2));
+        assert_eq!(result.answer, "42");
+
+        assert_eq!(
+            run_part(3, 2, |input| input.parse::<u32>().ok(), "x"),
+            Outcome::NotSolved
+        );
+        assert_eq!(
+            run_part(3, 2, |input| input.parse::<u32>(), "x"),
+            Outcome::Failed("invalid digit found in string".into())
         );
+    }
+
+    #[test]
+    fn test_run_both() {
+        let outcome = run_both(10, |input: &str| (input.len(), input.to_string()), "42");
+        let result = outcome.result().unwrap();
+        assert_eq!(result.part, report::BOTH);
+        assert_eq!(result.answer, "2");
+        assert_eq!(result.answer_two.as_deref(), Some("42"));
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+        assert_eq!(
+            run_both(10, |input: &str| input.parse::<u32>().map(|n| (n, n)), "x"),
+            Outcome::Failed("invalid digit found in string".into())
         );
     }
 }
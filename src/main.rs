@@ -2,45 +2,298 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::bench;
+use advent_of_code::data::{self, Answers};
+use advent_of_code::history::{self, Entry};
+use advent_of_code::readme;
+use advent_of_code::report::{self, Format, Outcome, Reporter};
+use advent_of_code::runner::{self, DayOutcome, Days, RunOptions};
+use std::process;
+use std::time::Duration;
+
+// defines `SOLUTIONS`, see `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+struct Args {
+    format: Format,
+    days: Days,
+    options: RunOptions,
+    compare: Option<Compare>,
+    update_readme: bool,
+    /// Write history and README timings of debug builds.
+    force: bool,
+    /// Do not append the timings of this run to the benchmark history.
+    no_history: bool,
+    /// Run every profile's inputs and check their recorded answers.
+    all_profiles: bool,
+    worker: bool,
+}
+
+/// Settings for comparing timings to the benchmark history.
+struct Compare {
+    /// Compare to the latest run at this commit instead of the latest run.
+    baseline: Option<String>,
+    /// Allowed slowdown in percent.
+    threshold: f64,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let compare = args.contains("--compare");
+    let baseline: Option<String> = args.opt_value_from_str("--baseline")?;
+    let threshold = args.opt_value_from_fn("--threshold", history::parse_threshold)?;
+
+    let parsed = Args {
+        format: args.opt_value_from_str("--format")?.unwrap_or(Format::Text),
+        days: args.opt_value_from_str("--days")?.unwrap_or_else(Days::all),
+        options: RunOptions {
+            parts: match args.opt_value_from_fn("--part", runner::parse_part)? {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            },
+            timeout: args.opt_value_from_fn("--timeout", runner::parse_timeout)?,
+            jobs: args
+                .opt_value_from_fn(["-j", "--jobs"], runner::parse_jobs)?
+                .unwrap_or(1),
+            bench: bench::parse_args(&mut args)?,
+            profile: args
+                .opt_value_from_str("--profile")?
+                .or_else(data::default_profile),
+        },
+        compare: (compare || baseline.is_some()).then(|| Compare {
+            baseline,
+            threshold: threshold.unwrap_or(10.0),
+        }),
+        update_readme: args.contains("--update-readme"),
+        force: args.contains("--force"),
+        no_history: args.contains("--no-history"),
+        all_profiles: args.contains("--all-profiles"),
+        worker: args.contains("--worker"),
+    };
+    advent_of_code::finish_args(args)?;
+    Ok(parsed)
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if args.update_readme && (args.days != Days::all() || args.options.parts != [1, 2]) {
+        eprintln!("--update-readme needs to run all days and parts, remove --days and --part.");
+        process::exit(1);
+    }
+
+    if args.update_readme && report::DEBUG_BUILD && !args.force {
+        eprintln!("Refusing to write timings of a debug build to README.md, run with --release or pass --force.");
+        process::exit(1);
+    }
+
+    if args.compare.is_some() && report::DEBUG_BUILD && !args.force {
+        eprintln!("Refusing to compare timings of a debug build to the benchmark history, run with --release or pass --force.");
+        process::exit(1);
+    }
+
+    if args.update_readme && args.options.profile.is_some() {
+        eprintln!("--update-readme publishes the timings of the default inputs, remove --profile and unset AOC_PROFILE.");
+        process::exit(1);
+    }
+
+    if args.all_profiles && (args.update_readme || args.compare.is_some()) {
+        eprintln!("--all-profiles cannot be combined with --update-readme or --compare.");
+        process::exit(1);
+    }
+
+    if args.all_profiles && args.format != Format::Text {
+        eprintln!("--all-profiles only supports the text format.");
+        process::exit(1);
+    }
+
+    let mut reporter = if args.worker {
+        Box::new(report::Worker)
+    } else {
+        args.format.reporter()
+    };
+
+    let mut outcomes: Vec<Outcome> = Vec::new();
+
+    if args.all_profiles {
+        let profiles = data::profiles();
+        if profiles.is_empty() {
+            eprintln!("No profiles found, put their inputs in src/inputs/<profile>/.");
+            process::exit(1);
+        }
+
+        for profile in profiles {
+            let answers = match data::answers(&profile) {
+                Ok(answers) => answers,
+                Err(e) => {
+                    eprintln!("Failed to read recorded answers: {}", e);
+                    process::exit(1);
                 }
+            };
+
+            reporter.start_profile(&profile);
+            let options = RunOptions {
+                profile: Some(profile),
+                ..args.options.clone()
+            };
+            run(
+                &args.days,
+                &options,
+                reporter.as_mut(),
+                &mut outcomes,
+                Some(&answers),
             );
+        }
+    } else {
+        run(
+            &args.days,
+            &args.options,
+            reporter.as_mut(),
+            &mut outcomes,
+            None,
+        );
+    }
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
-            }
-        })
+    let total: Duration = outcomes
+        .iter()
+        .filter_map(Outcome::result)
+        .map(|result| result.elapsed)
         .sum();
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+    reporter.finish(total);
+
+    let mut failed = outcomes.iter().any(Outcome::is_failure);
+
+    // timings of different inputs cannot be compared, every profile has its own history.
+    if !args.worker && !args.all_profiles {
+        let profile = args.options.profile.as_deref();
+        let commit = history::current_commit();
+        let date = history::current_date();
+        let entries: Vec<Entry> = outcomes
+            .iter()
+            .filter_map(Outcome::result)
+            .map(|result| Entry {
+                commit: commit.clone(),
+                date: date.clone(),
+                day: result.day,
+                part: result.part,
+                elapsed: result.elapsed,
+            })
+            .collect();
+
+        if let Some(compare) = &args.compare {
+            failed |= !compare_history(profile, &entries, compare);
+        }
+        if !args.no_history {
+            record_history(profile, &entries, args.force);
+        }
+    }
+
+    if args.update_readme {
+        let results: Vec<_> = outcomes.iter().filter_map(Outcome::result).collect();
+        if let Err(e) = readme::update(&readme::path(), &results) {
+            eprintln!("Failed to update README.md: {}", e);
+            failed = true;
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Runs the selected days and reports their parts. Solved parts are checked against `answers`, if given.
+fn run(
+    days: &Days,
+    options: &RunOptions,
+    reporter: &mut dyn Reporter,
+    outcomes: &mut Vec<Outcome>,
+    answers: Option<&Answers>,
+) {
+    runner::run_days(SOLUTIONS, days, options, |day, outcome| {
+        reporter.start_day(day);
+
+        match outcome {
+            DayOutcome::Skipped { parts, outcome } => {
+                reporter.skip_day(day, &parts, &outcome);
+                outcomes.push(outcome);
+            }
+            DayOutcome::Ran(parts) => {
+                for run in parts {
+                    let outcome = match answers {
+                        Some(answers) => runner::check_answer(answers, day, run.part, run.outcome),
+                        None => run.outcome,
+                    };
+                    reporter.part(day, run.part, &outcome);
+                    if !run.variants.is_empty() {
+                        reporter.variants(day, run.part, &run.variants);
+                    }
+                    outcomes.push(outcome);
+                }
+            }
+        }
+    });
+}
+
+/// Prints parts that got slower than the recorded history of `profile` allows. Returns `false` if there are any.
+fn compare_history(profile: Option<&str>, entries: &[Entry], compare: &Compare) -> bool {
+    let history = match history::load(&history::path(profile)) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if let Some(baseline) = &compare.baseline {
+        if !history
+            .iter()
+            .any(|entry| entry.commit.starts_with(baseline))
+        {
+            eprintln!("No recorded run at baseline \"{}\".", baseline);
+            process::exit(1);
+        }
+    }
+
+    let regressions = history::compare(
+        &history,
+        entries,
+        compare.baseline.as_deref(),
+        compare.threshold,
     );
+
+    for regression in &regressions {
+        eprintln!(
+            "Day {:02} part {} got {:.1}% slower: {:.2?} -> {:.2?} (compared to {} on {})",
+            regression.previous.day,
+            regression.previous.part,
+            regression.change,
+            regression.previous.elapsed,
+            regression.elapsed,
+            regression.previous.commit,
+            regression.previous.date
+        );
+    }
+
+    regressions.is_empty()
+}
+
+/// Appends `entries` to the benchmark history of `profile`, unless they were timed by a debug build and `force` is not set.
+fn record_history(profile: Option<&str>, entries: &[Entry], force: bool) {
+    if entries.is_empty() {
+        return;
+    }
+    if report::DEBUG_BUILD && !force {
+        eprintln!("Not recording timings of a debug build in the benchmark history, run with --release or pass --force.");
+        return;
+    }
+    if let Err(e) = history::append(&history::path(profile), entries) {
+        eprintln!("Failed to write benchmark history: {}", e);
+    }
 }
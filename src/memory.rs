@@ -0,0 +1,187 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+
+/// Heap usage of a part, counted when the `memory` feature is enabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    /// Number of allocations, including reallocations.
+    pub count: u64,
+    /// Bytes requested by all allocations.
+    pub bytes: u64,
+    /// The most bytes that were live at once, not counting memory allocated before the part started.
+    pub peak: u64,
+}
+
+impl fmt::Display for Allocations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.count,
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Runs `f` and counts the allocations it makes on the current thread.
+/// The counts are `None` unless the `memory` feature is enabled.
+pub fn track<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    #[cfg(feature = "memory")]
+    {
+        counting::track(f)
+    }
+    #[cfg(not(feature = "memory"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "memory")]
+mod counting {
+    use super::Allocations;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    /// Forwards to the system allocator and counts on the allocating thread, so that days run in parallel
+    /// by the `all` runner do not count each other's allocations.
+    struct Counting;
+
+    #[derive(Debug, Clone, Copy)]
+    struct Counters {
+        count: u64,
+        bytes: u64,
+        live: i64,
+        peak: i64,
+    }
+
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters {
+                count: 0,
+                bytes: 0,
+                live: 0,
+                peak: 0,
+            })
+        };
+    }
+
+    fn record(allocated: usize, freed: usize) {
+        // fails while the thread shuts down, those allocations are not counted.
+        let _ = COUNTERS.try_with(|counters| {
+            let mut current = counters.get();
+            if allocated > 0 {
+                current.count += 1;
+                current.bytes += allocated as u64;
+            }
+            current.live += allocated as i64 - freed as i64;
+            current.peak = current.peak.max(current.live);
+            counters.set(current);
+        });
+    }
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(layout.size(), 0);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(0, layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record(new_size, layout.size());
+            }
+            new_ptr
+        }
+    }
+
+    pub fn track<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+        let start = COUNTERS.with(|counters| {
+            let mut start = counters.get();
+            start.peak = start.live;
+            counters.set(start);
+            start
+        });
+
+        let value = f();
+        let end = COUNTERS.with(Cell::get);
+
+        let allocations = Allocations {
+            count: end.count - start.count,
+            bytes: end.bytes - start.bytes,
+            peak: (end.peak - start.live).max(0) as u64,
+        };
+        (value, Some(allocations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn test_track() {
+        let (len, allocations) = track(|| {
+            let mut values: Vec<u64> = Vec::with_capacity(16);
+            values.extend(0..16);
+            drop(values);
+            vec![0_u8; 64].len()
+        });
+        assert_eq!(len, 64);
+
+        if cfg!(feature = "memory") {
+            let allocations = allocations.unwrap();
+            assert_eq!(allocations.count, 2);
+            assert_eq!(allocations.bytes, 16 * 8 + 64);
+            assert_eq!(allocations.peak, 16 * 8);
+        } else {
+            assert_eq!(allocations, None);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::report::{PartResult, BOTH};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+const MARKER: &str = "<!--- benchmarking table --->";
+
+/// Location of the README, in the root of the repository.
+pub fn path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md")
+}
+
+/// Replaces the content between the two benchmarking table markers in the README with a table of `results`.
+pub fn update(path: &Path, results: &[&PartResult]) -> io::Result<()> {
+    let readme = fs::read_to_string(path)?;
+
+    let updated = replace_between_markers(&readme, &table(results)).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{} needs two \"{}\" lines to place the table between",
+                path.display(),
+                MARKER
+            ),
+        )
+    })?;
+
+    fs::write(path, updated)
+}
+
+fn replace_between_markers(readme: &str, content: &str) -> Option<String> {
+    let start = readme.find(MARKER)? + MARKER.len();
+    let end = start + readme[start..].find(MARKER)?;
+
+    Some(format!(
+        "{}\n{}{}",
+        &readme[..start],
+        content,
+        &readme[end..]
+    ))
+}
+
+fn table(results: &[&PartResult]) -> String {
+    // timings of the parse phase, both parts and both parts solved together, indexed by part number.
+    let mut days: BTreeMap<u8, [Option<Duration>; 4]> = BTreeMap::new();
+    for result in results {
+        if let Some(timing) = days
+            .entry(result.day)
+            .or_default()
+            .get_mut(usize::from(result.part))
+        {
+            *timing = Some(result.elapsed);
+        }
+    }
+
+    let mut table = String::from("## Benchmarks\n\n");
+    table.push_str("| Day | Parse | Part 1 | Part 2 | Total |\n");
+    table.push_str("| :---: | :---: | :---: | :---: | :---: |\n");
+
+    let mut total = Duration::ZERO;
+    for (day, timings) in &days {
+        let day_total: Duration = timings.iter().flatten().sum();
+        total += day_total;
+
+        let [parse, mut part_one, mut part_two, both] = timings.map(|timing| match timing {
+            Some(elapsed) => format!("`{:.2?}`", elapsed),
+            None => "-".into(),
+        });
+        if timings[usize::from(BOTH)].is_some() {
+            part_one = format!("{} (1 + 2)", both);
+            part_two = "-".into();
+        }
+        writeln!(
+            table,
+            "| [Day {}](./src/bin/{:02}.rs) | {} | {} | {} | `{:.2?}` |",
+            day, day, parse, part_one, part_two, day_total
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        table,
+        "\n**Total: {:.2}ms**",
+        total.as_secs_f64() * 1000_f64
+    )
+    .unwrap();
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(day: u8, part: u8, micros: u64) -> PartResult {
+        PartResult::new(day, part, "", Duration::from_micros(micros))
+    }
+
+    #[test]
+    fn test_table() {
+        let results = [
+            result(9, 1, 150),
+            result(9, 2, 250),
+            result(2, 2, 1100),
+            result(7, 0, 50),
+            result(7, 1, 10),
+            result(10, 3, 40),
+        ];
+        let results: Vec<&PartResult> = results.iter().collect();
+
+        assert_eq!(
+            table(&results),
+            "## Benchmarks
+
+| Day | Parse | Part 1 | Part 2 | Total |
+| :---: | :---: | :---: | :---: | :---: |
+| [Day 2](./src/bin/02.rs) | - | - | `1.10ms` | `1.10ms` |
+| [Day 7](./src/bin/07.rs) | `50.00µs` | `10.00µs` | - | `60.00µs` |
+| [Day 9](./src/bin/09.rs) | - | `150.00µs` | `250.00µs` | `400.00µs` |
+| [Day 10](./src/bin/10.rs) | - | `40.00µs` (1 + 2) | - | `40.00µs` |
+
+**Total: 1.60ms**
+"
+        );
+    }
+
+    #[test]
+    fn test_replace_between_markers() {
+        let readme = format!("# AoC\n{}\nold table\n{}\nfooter\n", MARKER, MARKER);
+        assert_eq!(
+            replace_between_markers(&readme, "new table\n"),
+            Some(format!(
+                "# AoC\n{}\nnew table\n{}\nfooter\n",
+                MARKER, MARKER
+            ))
+        );
+        assert_eq!(replace_between_markers(&format!("{}\n", MARKER), ""), None);
+    }
+}
@@ -0,0 +1,813 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::Stats;
+use crate::memory::Allocations;
+use crate::runner::Panic;
+use crate::spans::Span;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::{str::FromStr, time::Duration};
+
+/// Whether this is an unoptimized build. Its timings are not representative and get tagged with "(debug)".
+pub const DEBUG_BUILD: bool = cfg!(debug_assertions);
+
+/// Stands in for the part number of a day's parse phase. Its results have an empty answer.
+pub const PARSE: u8 = 0;
+
+/// Stands in for the part number of days that solve both parts together.
+/// Their results carry the answer to part two in [`PartResult::answer_two`].
+pub const BOTH: u8 = 3;
+
+/// The answer to a single part, together with the time it took to compute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// The answer to part two if both parts were solved together, see [`BOTH`].
+    pub answer_two: Option<String>,
+    /// The median of all runs in benchmark mode.
+    pub elapsed: Duration,
+    /// Timings of all runs, `None` unless the part was benchmarked.
+    pub stats: Option<Stats>,
+    /// Heap usage of the part, `None` unless built with the `memory` feature.
+    pub allocations: Option<Allocations>,
+    /// Sections timed with `span!`, empty unless the day binary was started with `--spans`.
+    pub spans: Vec<Span>,
+}
+
+#[cfg(test)]
+impl PartResult {
+    /// A solved part without benchmark stats, allocations or spans.
+    pub(crate) fn new(day: u8, part: u8, answer: &str, elapsed: Duration) -> PartResult {
+        PartResult {
+            day,
+            part,
+            answer: answer.into(),
+            answer_two: None,
+            elapsed,
+            stats: None,
+            allocations: None,
+            spans: Vec::new(),
+        }
+    }
+}
+
+/// What happened when a part was run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(PartResult),
+    NotSolved,
+    Panicked(Panic),
+    MissingInput,
+    TimedOut(Duration),
+    /// The variants of a part returned different answers, or the answer differs from the recorded one.
+    Mismatch(String),
+    /// The part returned an error.
+    Failed(String),
+}
+
+impl Outcome {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Outcome::Solved(_) => "solved",
+            Outcome::NotSolved => "not_solved",
+            Outcome::Panicked(_) => "panicked",
+            Outcome::MissingInput => "missing_input",
+            Outcome::TimedOut(_) => "timed_out",
+            Outcome::Mismatch(_) => "mismatch",
+            Outcome::Failed(_) => "failed",
+        }
+    }
+
+    pub fn result(&self) -> Option<&PartResult> {
+        match self {
+            Outcome::Solved(result) => Some(result),
+            _ => None,
+        }
+    }
+
+    /// Describes why a part failed. `None` unless [`Outcome::is_failure`].
+    pub fn error(&self) -> Option<String> {
+        match self {
+            Outcome::Panicked(panic) => Some(panic.to_string()),
+            Outcome::MissingInput => Some("could not open input file".into()),
+            Outcome::TimedOut(timeout) => Some(format!("timed out after {:?}", timeout)),
+            Outcome::Mismatch(message) | Outcome::Failed(message) => Some(message.clone()),
+            _ => None,
+        }
+    }
+
+    /// Failures make the `all` runner exit with a non-zero status.
+    /// Parts that return `None` are not considered failures.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Panicked(_)
+                | Outcome::MissingInput
+                | Outcome::TimedOut(_)
+                | Outcome::Mismatch(_)
+                | Outcome::Failed(_)
+        )
+    }
+}
+
+/// Receives results from `solve!` and the `all` runner and presents them.
+pub trait Reporter {
+    /// Called with `--all-profiles` before the days of a profile are reported.
+    fn start_profile(&mut self, _profile: &str) {}
+
+    /// Called before the parts of a day are reported.
+    fn start_day(&mut self, _day: u8) {}
+
+    /// Called for every part that ran.
+    fn part(&mut self, day: u8, part: u8, outcome: &Outcome);
+
+    /// Called after [`Reporter::part`] for parts with named variants, with the outcome of each variant.
+    fn variants(&mut self, _day: u8, _part: u8, _variants: &[(String, Outcome)]) {}
+
+    /// Called instead of [`Reporter::part`] when the selected `parts` of a day could not be run at all.
+    fn skip_day(&mut self, day: u8, parts: &[u8], outcome: &Outcome) {
+        for &part in parts {
+            self.part(day, part, outcome);
+        }
+    }
+
+    /// Called once all days have been reported.
+    fn finish(&mut self, _total: Duration) {}
+}
+
+/// Output formats supported by the `all` runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn reporter(self) -> Box<dyn Reporter> {
+        match self {
+            Format::Text => Box::new(Terminal),
+            Format::Json => Box::<Json>::default(),
+            Format::Csv => Box::<Csv>::default(),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected text, json or csv",
+                s
+            )),
+        }
+    }
+}
+
+/// Human-readable, ANSI-decorated output.
+#[derive(Debug, Default)]
+pub struct Terminal;
+
+impl Reporter for Terminal {
+    fn start_profile(&mut self, profile: &str) {
+        println!("==========");
+        println!("{}| Profile {} |{}", ANSI_BOLD, profile, ANSI_RESET);
+        println!("==========");
+    }
+
+    fn start_day(&mut self, day: u8) {
+        println!("----------");
+        println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+        println!("----------");
+    }
+
+    fn part(&mut self, _day: u8, part: u8, outcome: &Outcome) {
+        match part {
+            PARSE => println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET),
+            BOTH => println!("🎄 {}Part 1 + 2{} 🎄", ANSI_BOLD, ANSI_RESET),
+            _ => println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET),
+        }
+        match outcome {
+            Outcome::Solved(result) if result.answer.is_empty() => {
+                println!("{}{}{}", ANSI_ITALIC, timing(result), ANSI_RESET)
+            }
+            Outcome::Solved(PartResult {
+                answer,
+                answer_two: Some(answer_two),
+                ..
+            }) => println!(
+                "{}\n{} {}{}{}",
+                answer,
+                answer_two,
+                ANSI_ITALIC,
+                timing(outcome.result().unwrap()),
+                ANSI_RESET
+            ),
+            Outcome::Solved(result) => println!(
+                "{} {}{}{}",
+                result.answer,
+                ANSI_ITALIC,
+                timing(result),
+                ANSI_RESET
+            ),
+            Outcome::NotSolved => println!("not solved."),
+            Outcome::Panicked(panic) => println!("{}", excerpt(&panic.to_string())),
+            Outcome::MissingInput => println!("input missing."),
+            Outcome::TimedOut(timeout) => println!("timed out after {:?}.", timeout),
+            Outcome::Mismatch(message) => println!("{}", excerpt(message)),
+            Outcome::Failed(error) => println!("error: {}", excerpt(error)),
+        }
+        if let Some(result) = outcome.result() {
+            print_spans(result);
+        }
+    }
+
+    fn variants(&mut self, _day: u8, _part: u8, variants: &[(String, Outcome)]) {
+        let width = variants
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        let baseline = variants.first().and_then(|(_, outcome)| outcome.result());
+
+        for (index, (name, outcome)) in variants.iter().enumerate() {
+            let timing = match outcome.result() {
+                Some(result) => format!("{:.2?}", result.elapsed),
+                None => outcome.status().replace('_', " "),
+            };
+            // timings of later variants are shown relative to the first one.
+            let relative = match (baseline, outcome.result()) {
+                (Some(baseline), Some(result)) if index > 0 => format!(
+                    " ({:.2}x)",
+                    result.elapsed.as_secs_f64() / baseline.elapsed.as_secs_f64()
+                ),
+                _ => String::new(),
+            };
+            let allocations = outcome
+                .result()
+                .and_then(|result| result.allocations)
+                .map_or(String::new(), |allocations| format!(", {}", allocations));
+            println!(
+                "  {:<width$}  {}{}{}{}{}{}",
+                name,
+                ANSI_ITALIC,
+                timing,
+                relative,
+                allocations,
+                debug_tag(),
+                ANSI_RESET,
+                width = width
+            );
+        }
+    }
+
+    fn skip_day(&mut self, _day: u8, _parts: &[u8], outcome: &Outcome) {
+        match outcome {
+            Outcome::MissingInput => println!("Input missing."),
+            Outcome::Failed(error) => println!("Error: {}", excerpt(error)),
+            _ => println!("Not solved."),
+        }
+    }
+
+    fn finish(&mut self, total: Duration) {
+        println!(
+            "{}Total:{} {}{:.2}ms{}{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            debug_tag(),
+            ANSI_RESET
+        );
+    }
+}
+
+fn timing(result: &PartResult) -> String {
+    let timing = match result.stats {
+        Some(stats) => format!(
+            "median: {:.2?}, min: {:.2?}, mean: {:.2?} ± {:.2?}, p95: {:.2?}, {} iterations",
+            stats.median, stats.min, stats.mean, stats.stddev, stats.p95, stats.iterations
+        ),
+        None => format!("elapsed: {:.2?}", result.elapsed),
+    };
+    match result.allocations {
+        Some(allocations) => format!("({}, {}){}", timing, allocations, debug_tag()),
+        None => format!("({}){}", timing, debug_tag()),
+    }
+}
+
+fn debug_tag() -> &'static str {
+    if DEBUG_BUILD {
+        " (debug)"
+    } else {
+        ""
+    }
+}
+
+/// Prints the time spent in each span of a part, and its share of the part's time.
+fn print_spans(result: &PartResult) {
+    // benchmarked spans are averaged per run, so they are compared to the mean rather than the median.
+    let elapsed = result.stats.map_or(result.elapsed, |stats| stats.mean);
+    let width = result
+        .spans
+        .iter()
+        .map(|span| span.name.len())
+        .max()
+        .unwrap_or(0);
+
+    for span in &result.spans {
+        println!(
+            "  {:<width$}  {}{:.2?} ({:.1}%, {} {}){}{}",
+            span.name,
+            ANSI_ITALIC,
+            span.elapsed,
+            span.elapsed.as_secs_f64() / elapsed.as_secs_f64() * 100.0,
+            span.calls,
+            if span.calls == 1 { "call" } else { "calls" },
+            debug_tag(),
+            ANSI_RESET,
+            width = width
+        );
+    }
+}
+
+/// Shortens multi-line failure messages for terminal output.
+fn excerpt(message: &str) -> String {
+    const MAX_LINES: usize = 5;
+
+    let mut lines = message.lines();
+    let mut excerpt = lines
+        .by_ref()
+        .take(MAX_LINES)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let remaining = lines.count();
+    if remaining > 0 {
+        excerpt.push_str(&format!("\n... ({} more lines)", remaining));
+    }
+    excerpt
+}
+
+/// A JSON array with one object per day and part.
+#[derive(Debug, Default)]
+pub struct Json {
+    records: usize,
+}
+
+impl Reporter for Json {
+    fn part(&mut self, day: u8, part: u8, outcome: &Outcome) {
+        let separator = if self.records == 0 { "[" } else { "," };
+        print!(
+            "{}\n  {}",
+            separator,
+            json_record(day, part, outcome, DEBUG_BUILD)
+        );
+        self.records += 1;
+    }
+
+    fn finish(&mut self, _total: Duration) {
+        if self.records == 0 {
+            println!("[]");
+        } else {
+            println!("\n]");
+        }
+    }
+}
+
+/// `debug` marks timings of a debug build, which are not comparable to release timings.
+fn json_record(day: u8, part: u8, outcome: &Outcome, debug: bool) -> String {
+    let (answer, answer_two, elapsed) = match outcome.result() {
+        Some(result) => (
+            json_string(&result.answer),
+            result
+                .answer_two
+                .as_deref()
+                .map_or("null".into(), json_string),
+            result.elapsed.as_nanos().to_string(),
+        ),
+        None => ("null".into(), "null".into(), "null".into()),
+    };
+
+    let [allocations, bytes, peak] = allocation_fields(outcome).map(|field| match field {
+        Some(value) => value.to_string(),
+        None => "null".into(),
+    });
+
+    let error = outcome
+        .error()
+        .map_or("null".into(), |error| json_string(&error));
+
+    format!(
+        "{{\"day\": {}, \"part\": {}, \"status\": \"{}\", \"answer\": {}, \"answer_two\": {}, \"elapsed_ns\": {}, \"debug\": {}, \"allocations\": {}, \"allocated_bytes\": {}, \"peak_bytes\": {}, \"error\": {}}}",
+        day,
+        part,
+        outcome.status(),
+        answer,
+        answer_two,
+        elapsed,
+        debug,
+        allocations,
+        bytes,
+        peak,
+        error
+    )
+}
+
+/// The allocation count, allocated bytes and peak bytes of solved parts built with the `memory` feature.
+fn allocation_fields(outcome: &Outcome) -> [Option<u64>; 3] {
+    match outcome.result().and_then(|result| result.allocations) {
+        Some(allocations) => [
+            Some(allocations.count),
+            Some(allocations.bytes),
+            Some(allocations.peak),
+        ],
+        None => [None; 3],
+    }
+}
+
+fn json_string(val: &str) -> String {
+    let mut escaped = String::with_capacity(val.len() + 2);
+    escaped.push('"');
+    for c in val.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Comma-separated values with a header row and one row per day and part.
+#[derive(Debug, Default)]
+pub struct Csv {
+    header_written: bool,
+}
+
+impl Reporter for Csv {
+    fn part(&mut self, day: u8, part: u8, outcome: &Outcome) {
+        if !self.header_written {
+            println!("day,part,status,answer,answer_two,elapsed_ns,debug,allocations,allocated_bytes,peak_bytes,error");
+            self.header_written = true;
+        }
+        println!("{}", csv_record(day, part, outcome, DEBUG_BUILD));
+    }
+}
+
+fn csv_record(day: u8, part: u8, outcome: &Outcome, debug: bool) -> String {
+    let (answer, answer_two, elapsed) = match outcome.result() {
+        Some(result) => (
+            csv_field(&result.answer),
+            result
+                .answer_two
+                .as_deref()
+                .map_or(String::new(), csv_field),
+            result.elapsed.as_nanos().to_string(),
+        ),
+        None => (String::new(), String::new(), String::new()),
+    };
+
+    let [allocations, bytes, peak] = allocation_fields(outcome)
+        .map(|field| field.map_or(String::new(), |value| value.to_string()));
+
+    let error = outcome
+        .error()
+        .map_or(String::new(), |error| csv_field(&error));
+
+    format!(
+        "{},{},{},{},{},{},{},{},{},{},{}",
+        day,
+        part,
+        outcome.status(),
+        answer,
+        answer_two,
+        elapsed,
+        debug,
+        allocations,
+        bytes,
+        peak,
+        error
+    )
+}
+
+fn csv_field(val: &str) -> String {
+    if val.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", val.replace('"', "\"\""))
+    } else {
+        val.to_owned()
+    }
+}
+
+/// Marks the lines of a worker's stdout that carry part results.
+const WORKER_RECORD_PREFIX: &str = "@aoc-worker";
+
+/// Machine-readable records that worker processes send back to the `all` runner.
+#[derive(Debug, Default)]
+pub struct Worker;
+
+impl Reporter for Worker {
+    fn part(&mut self, day: u8, part: u8, outcome: &Outcome) {
+        println!("{}", encode_record(day, part, None, outcome));
+    }
+
+    fn variants(&mut self, day: u8, part: u8, variants: &[(String, Outcome)]) {
+        for (name, outcome) in variants {
+            println!("{}", encode_record(day, part, Some(name), outcome));
+        }
+    }
+}
+
+/// A part result sent by a worker process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// Name of the variant, `None` for the part's overall outcome.
+    pub variant: Option<String>,
+    pub outcome: Outcome,
+}
+
+fn encode_record(day: u8, part: u8, variant: Option<&str>, outcome: &Outcome) -> String {
+    let stats = match outcome.result().and_then(|result| result.stats) {
+        Some(stats) => [stats.min, stats.median, stats.mean, stats.stddev, stats.p95]
+            .iter()
+            .map(|duration| duration.as_nanos().to_string())
+            .chain([stats.iterations.to_string()])
+            .collect::<Vec<_>>()
+            .join(","),
+        None => String::new(),
+    };
+
+    let (elapsed, payload, location) = match outcome {
+        Outcome::Solved(result) => (
+            result.elapsed.as_nanos().to_string(),
+            result.answer.as_str(),
+            "",
+        ),
+        Outcome::Panicked(panic) => (
+            String::new(),
+            panic.message.as_str(),
+            panic.location.as_deref().unwrap_or_default(),
+        ),
+        Outcome::TimedOut(timeout) => (timeout.as_nanos().to_string(), "", ""),
+        Outcome::Mismatch(message) | Outcome::Failed(message) => {
+            (String::new(), message.as_str(), "")
+        }
+        _ => (String::new(), "", ""),
+    };
+
+    [
+        WORKER_RECORD_PREFIX,
+        &day.to_string(),
+        &part.to_string(),
+        &escape_field(variant.unwrap_or_default()),
+        outcome.status(),
+        &elapsed,
+        &escape_field(payload),
+        &escape_field(location),
+        &stats,
+        &outcome
+            .result()
+            .and_then(|result| result.answer_two.as_deref())
+            .map_or(String::new(), escape_field),
+        &allocation_fields(outcome)
+            .iter()
+            .flatten()
+            .map(u64::to_string)
+            .collect::<Vec<_>>()
+            .join(","),
+    ]
+    .join("\t")
+}
+
+/// Parses a line written by the [`Worker`] reporter. Returns `None` for any other line.
+/// Output that a solution printed without a trailing newline is skipped.
+pub fn decode_record(line: &str) -> Option<Record> {
+    let start = line.find(WORKER_RECORD_PREFIX)?;
+    let mut fields = line[start..].split('\t');
+    if fields.next()? != WORKER_RECORD_PREFIX {
+        return None;
+    }
+
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let variant = Some(unescape_field(fields.next()?)).filter(|variant| !variant.is_empty());
+    let status = fields.next()?;
+    let elapsed = fields.next()?.parse().ok().map(Duration::from_nanos);
+    let payload = unescape_field(fields.next()?);
+    let location = unescape_field(fields.next()?);
+    let stats = decode_stats(fields.next()?);
+    // an empty field could also be an empty answer, but only parse phases have those.
+    let answer_two = Some(unescape_field(fields.next()?)).filter(|answer| !answer.is_empty());
+    let allocations = decode_allocations(fields.next()?);
+
+    let outcome = match status {
+        "solved" => Outcome::Solved(PartResult {
+            day,
+            part,
+            answer: payload,
+            answer_two,
+            elapsed: elapsed?,
+            stats,
+            allocations,
+            spans: Vec::new(),
+        }),
+        "not_solved" => Outcome::NotSolved,
+        "panicked" => Outcome::Panicked(Panic {
+            message: payload,
+            location: Some(location).filter(|location| !location.is_empty()),
+        }),
+        "missing_input" => Outcome::MissingInput,
+        "timed_out" => Outcome::TimedOut(elapsed?),
+        "mismatch" => Outcome::Mismatch(payload),
+        "failed" => Outcome::Failed(payload),
+        _ => return None,
+    };
+
+    Some(Record {
+        day,
+        part,
+        variant,
+        outcome,
+    })
+}
+
+fn decode_stats(field: &str) -> Option<Stats> {
+    let values = field
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<Vec<u64>, _>>()
+        .ok()?;
+    let [min, median, mean, stddev, p95, iterations] = values[..] else {
+        return None;
+    };
+
+    Some(Stats {
+        iterations: iterations as usize,
+        min: Duration::from_nanos(min),
+        median: Duration::from_nanos(median),
+        mean: Duration::from_nanos(mean),
+        stddev: Duration::from_nanos(stddev),
+        p95: Duration::from_nanos(p95),
+    })
+}
+
+fn decode_allocations(field: &str) -> Option<Allocations> {
+    let values = field
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<Vec<u64>, _>>()
+        .ok()?;
+    let [count, bytes, peak] = values[..] else {
+        return None;
+    };
+
+    Some(Allocations { count, bytes, peak })
+}
+
+fn escape_field(val: &str) -> String {
+    val.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn unescape_field(val: &str) -> String {
+    let mut unescaped = String::with_capacity(val.len());
+    let mut chars = val.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solved(answer: &str) -> Outcome {
+        Outcome::Solved(PartResult::new(10, 2, answer, Duration::from_nanos(1500)))
+    }
+
+    #[test]
+    fn test_json_record() {
+        assert_eq!(
+            json_record(10, 2, &solved("#.\n\"x\""), false),
+            r##"{"day": 10, "part": 2, "status": "solved", "answer": "#.\n\"x\"", "answer_two": null, "elapsed_ns": 1500, "debug": false, "allocations": null, "allocated_bytes": null, "peak_bytes": null, "error": null}"##
+        );
+        assert_eq!(
+            json_record(3, 1, &Outcome::MissingInput, true),
+            r#"{"day": 3, "part": 1, "status": "missing_input", "answer": null, "answer_two": null, "elapsed_ns": null, "debug": true, "allocations": null, "allocated_bytes": null, "peak_bytes": null, "error": "could not open input file"}"#
+        );
+    }
+
+    #[test]
+    fn test_csv_record() {
+        assert_eq!(
+            csv_record(10, 2, &solved("42"), false),
+            "10,2,solved,42,,1500,false,,,,"
+        );
+        assert_eq!(
+            csv_record(10, 2, &solved("a,\"b\"\nc"), true),
+            "10,2,solved,\"a,\"\"b\"\"\nc\",,1500,true,,,,"
+        );
+        let panicked = Outcome::Panicked(Panic {
+            message: "oops, \"x\"".into(),
+            location: Some("src/bin/03.rs:4:5".into()),
+        });
+        assert_eq!(
+            csv_record(3, 1, &panicked, false),
+            "3,1,panicked,,,,false,,,,\"panicked at src/bin/03.rs:4:5: oops, \"\"x\"\"\""
+        );
+    }
+
+    #[test]
+    fn test_worker_record_roundtrip() {
+        let benched = Outcome::Solved(PartResult {
+            stats: Some(Stats {
+                iterations: 100,
+                min: Duration::from_nanos(1200),
+                median: Duration::from_nanos(1500),
+                mean: Duration::from_nanos(1600),
+                stddev: Duration::from_nanos(300),
+                p95: Duration::from_nanos(2100),
+            }),
+            ..solved("42").result().unwrap().clone()
+        });
+
+        let both = Outcome::Solved(PartResult {
+            answer_two: Some("##.\n.##".into()),
+            allocations: Some(Allocations {
+                count: 12,
+                bytes: 4096,
+                peak: 1024,
+            }),
+            ..solved("13140").result().unwrap().clone()
+        });
+
+        let outcomes = [
+            solved("#.\n\t\\x"),
+            benched,
+            both,
+            Outcome::NotSolved,
+            Outcome::Panicked(Panic {
+                message: "line 1\nline 2".into(),
+                location: Some("src/bin/03.rs:4:5".into()),
+            }),
+            Outcome::Panicked(Panic {
+                message: "stack overflow".into(),
+                location: None,
+            }),
+            Outcome::MissingInput,
+            Outcome::TimedOut(Duration::from_secs(3)),
+            Outcome::Mismatch("variants disagree: a = 1, b = 2".into()),
+            Outcome::Failed("line 3: invalid digit found in string (\"1x\")".into()),
+        ];
+
+        for outcome in outcomes {
+            for variant in [None, Some("linked-list")] {
+                let line = encode_record(10, 2, variant, &outcome);
+                assert_eq!(line.lines().count(), 1);
+                assert_eq!(
+                    decode_record(&line),
+                    Some(Record {
+                        day: 10,
+                        part: 2,
+                        variant: variant.map(String::from),
+                        outcome: outcome.clone(),
+                    })
+                );
+            }
+        }
+
+        assert_eq!(decode_record("48381165"), None);
+        let line = encode_record(10, 2, None, &solved("42"));
+        assert_eq!(
+            decode_record(&format!("48381165{}", line)),
+            decode_record(&line)
+        );
+    }
+}
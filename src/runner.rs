@@ -0,0 +1,789 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::{self, Bench};
+use crate::data::{self, Answers};
+use crate::memory;
+use crate::report::{self, Outcome, PartResult, BOTH, PARSE};
+use crate::spans;
+use crate::{Input, Solution, Variant};
+use std::{
+    cell::{Cell, RefCell},
+    collections::BTreeMap,
+    env, fmt,
+    io::Read,
+    panic::{self, AssertUnwindSafe, PanicHookInfo},
+    process::{Command, ExitStatus, Stdio},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Days selected for the `all` runner, e.g. `1-10,15`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Days(Vec<u8>);
+
+impl Days {
+    pub fn all() -> Self {
+        Days((1..=25).collect())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = Vec::new();
+
+        for range in s.split(',').map(str::trim) {
+            let (start, end) = range.split_once('-').unwrap_or((range, range));
+            let start = parse_day(start)?;
+            let end = parse_day(end)?;
+            if start > end {
+                return Err(format!("invalid day range \"{}\"", range));
+            }
+            days.extend(start..=end);
+        }
+
+        days.sort_unstable();
+        days.dedup();
+        Ok(Days(days))
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("\"{}\" is not a day between 1 and 25", s)),
+    }
+}
+
+/// Parses the `--part` argument.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("\"{}\" is not a part, expected 1 or 2", s)),
+    }
+}
+
+/// Parses the `--timeout` argument, given in (fractional) seconds.
+pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+    match s.parse::<f64>() {
+        Ok(secs) if secs > 0_f64 && secs.is_finite() => Ok(Duration::from_secs_f64(secs)),
+        _ => Err(format!("\"{}\" is not a positive number of seconds", s)),
+    }
+}
+
+/// Parses the `--jobs` argument.
+pub fn parse_jobs(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(jobs) if jobs > 0 => Ok(jobs),
+        _ => Err(format!("\"{}\" is not a positive number of jobs", s)),
+    }
+}
+
+/// Settings shared by all days of a run.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    pub parts: Vec<u8>,
+    /// Run every part in a worker process that is killed after this duration.
+    pub timeout: Option<Duration>,
+    /// Number of days to run concurrently.
+    pub jobs: usize,
+    /// Run every part repeatedly and report the median.
+    pub bench: Option<Bench>,
+    /// Read the inputs of this profile, see [`data::inputs_folder`].
+    pub profile: Option<String>,
+}
+
+/// What happened when a day was run.
+// there is one outcome per day, boxing the skipped outcome would not save anything.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DayOutcome {
+    /// None of the parts could be run, e.g. because the input is missing.
+    /// `parts` are the parts the day would have been reported as, e.g. [`BOTH`] for days with `solve_both`.
+    Skipped { parts: Vec<u8>, outcome: Outcome },
+    /// The parse phase, if the day has one, followed by the selected parts in order.
+    /// The parts are left out if parsing failed.
+    Ran(Vec<PartRun>),
+}
+
+/// What happened when a part was run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub part: u8,
+    pub outcome: Outcome,
+    /// Outcomes of the part's named variants, if it has any.
+    pub variants: Vec<(String, Outcome)>,
+}
+
+/// The parts a day is run and reported as: [`BOTH`] for days with `solve_both` when both parts are selected,
+/// otherwise the selected parts, which such days solve by calling `solve_both` for each of them.
+fn day_parts(solution: &dyn Solution, options: &RunOptions) -> Vec<u8> {
+    match solution.solves_both() && options.parts == [1, 2] {
+        true => vec![BOTH],
+        false => options.parts.clone(),
+    }
+}
+
+/// Runs the selected parts of a day.
+/// Unless the parts run in worker processes with a timeout, whatever they print goes to the runner's stdout.
+pub fn run_day(solution: &dyn Solution, options: &RunOptions) -> DayOutcome {
+    let day = solution.day();
+    let parts = day_parts(solution, options);
+
+    let folder = data::inputs_folder(options.profile.as_deref());
+    let input = match data::read(&folder, day, solution.normalize()) {
+        Ok(input) => input,
+        Err(data::ReadError::NotFound(_)) => {
+            return DayOutcome::Skipped {
+                parts,
+                outcome: Outcome::MissingInput,
+            }
+        }
+        Err(e) => {
+            return DayOutcome::Skipped {
+                parts,
+                outcome: Outcome::Failed(e.to_string()),
+            }
+        }
+    };
+
+    if let Some(timeout) = options.timeout {
+        let mut runs: Vec<PartRun> = Vec::new();
+        for &part in &parts {
+            for run in run_worker(day, part, timeout, options) {
+                if run.part == PARSE {
+                    // every worker parses the input, only the first parse is reported, ahead of the parts.
+                    if !runs.iter().any(|run| run.part == PARSE) {
+                        runs.insert(0, run);
+                    }
+                    continue;
+                }
+                runs.push(run);
+            }
+        }
+        return DayOutcome::Ran(runs);
+    }
+
+    let mut runs = Vec::new();
+
+    let parsed = match solution.parser() {
+        Some(parse) => {
+            let (run, parsed) = run_parse(day, parse, &input, options.bench);
+            runs.push(run);
+            match parsed {
+                Some(parsed) => Some(parsed),
+                None => return DayOutcome::Ran(runs),
+            }
+        }
+        None => None,
+    };
+
+    let input = match &parsed {
+        Some(parsed) => Input::Parsed(parsed.as_ref()),
+        None => Input::Text(&input),
+    };
+
+    for part in parts {
+        runs.push(match part {
+            BOTH => run_isolated(BOTH, || {
+                bench::measure(options.bench, || solution.solve_both(input))
+            }),
+            part => run_part(solution, part, input, options.bench),
+        });
+    }
+
+    DayOutcome::Ran(runs)
+}
+
+/// Runs and times the parse phase of a day. The parsed input is `None` if `parse` panicked or returned an error.
+pub fn run_parse<P, E: fmt::Display>(
+    day: u8,
+    parse: impl Fn(&str) -> Result<P, E>,
+    input: &str,
+    bench: Option<Bench>,
+) -> (PartRun, Option<P>) {
+    let mut parsed = None;
+
+    let run = run_isolated(PARSE, || {
+        bench::measure(bench, || {
+            let ((value, elapsed), allocations) = memory::track(|| {
+                let timer = Instant::now();
+                let value = parse(input);
+                (value, timer.elapsed())
+            });
+            let spans = spans::take();
+
+            let value = match value {
+                Ok(value) => value,
+                Err(error) => return Outcome::Failed(format!("{:#}", error)),
+            };
+            parsed = Some(value);
+            Outcome::Solved(PartResult {
+                day,
+                part: PARSE,
+                answer: String::new(),
+                answer_two: None,
+                elapsed,
+                stats: None,
+                allocations,
+                spans,
+            })
+        })
+    });
+
+    // a panic in a later benchmark run leaves the value of an earlier one.
+    let parsed = parsed.filter(|_| !run.outcome.is_failure());
+    (run, parsed)
+}
+
+/// Runs a single part in the current process.
+pub fn run_part(solution: &dyn Solution, part: u8, input: Input, bench: Option<Bench>) -> PartRun {
+    let variants = solution.variants(part);
+    if variants.len() > 1 {
+        return run_variants(part, &variants, input.text(), bench);
+    }
+
+    run_isolated(part, || {
+        bench::measure(bench, || match part {
+            1 => solution.part_one(input),
+            _ => solution.part_two(input),
+        })
+    })
+}
+
+/// Runs `solver`, turning a panic into [`Outcome::Panicked`] so that the remaining parts still run.
+pub fn run_isolated(part: u8, solver: impl FnOnce() -> Outcome) -> PartRun {
+    let outcome = catch_panic(solver).unwrap_or_else(Outcome::Panicked);
+
+    PartRun {
+        part,
+        outcome,
+        variants: Vec::new(),
+    }
+}
+
+/// Runs every variant of a part and checks that they agree on the answer.
+/// The part's outcome is the first variant's, or [`Outcome::Mismatch`] if any answer differs.
+pub fn run_variants(part: u8, variants: &[Variant], input: &str, bench: Option<Bench>) -> PartRun {
+    let variants: Vec<(String, Outcome)> = variants
+        .iter()
+        .map(|variant| {
+            let outcome = catch_panic(|| bench::measure(bench, || (variant.solver)(input)))
+                .unwrap_or_else(Outcome::Panicked);
+            (variant.name.to_string(), outcome)
+        })
+        .collect();
+
+    PartRun {
+        part,
+        outcome: cross_check(&variants),
+        variants,
+    }
+}
+
+fn cross_check(variants: &[(String, Outcome)]) -> Outcome {
+    let answer = |outcome: &Outcome| outcome.result().map(|result| result.answer.clone());
+
+    let (_, first) = &variants[0];
+    if variants
+        .iter()
+        .all(|(_, outcome)| answer(outcome) == answer(first))
+    {
+        return first.clone();
+    }
+
+    let answers: Vec<String> = variants
+        .iter()
+        .map(|(name, outcome)| {
+            let answer = answer(outcome).unwrap_or_else(|| outcome.status().replace('_', " "));
+            format!("{} = {}", name, answer)
+        })
+        .collect();
+    Outcome::Mismatch(format!("variants disagree: {}", answers.join(", ")))
+}
+
+/// Turns a solved part whose answer differs from the one recorded in `answers` into [`Outcome::Mismatch`].
+/// Parts without a recorded answer are left as they are.
+pub fn check_answer(answers: &Answers, day: u8, part: u8, outcome: Outcome) -> Outcome {
+    let Outcome::Solved(result) = &outcome else {
+        return outcome;
+    };
+
+    let checks = match part {
+        PARSE => vec![],
+        BOTH => vec![(1, Some(&result.answer)), (2, result.answer_two.as_ref())],
+        part => vec![(part, Some(&result.answer))],
+    };
+
+    let wrong: Vec<String> = checks
+        .into_iter()
+        .filter_map(|(part, answer)| {
+            let expected = answers.get(&(day, part))?;
+            let answer = answer.map(String::as_str).unwrap_or_default();
+            (answer != expected)
+                .then(|| format!("part {}: expected {:?}, got {:?}", part, expected, answer))
+        })
+        .collect();
+
+    if wrong.is_empty() {
+        outcome
+    } else {
+        Outcome::Mismatch(wrong.join(", "))
+    }
+}
+
+/// Stack size of the threads that run days with `--jobs`, matching the usual 8 MiB of the main thread,
+/// so that deeply recursive solutions behave the same as with `cargo solve`.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Runs `days` on up to `options.jobs` threads and hands their outcomes to `report` in day order.
+/// With a single job, days run on the calling thread.
+/// Days without a solution are reported as [`Outcome::NotSolved`].
+pub fn run_days(
+    solutions: &[&dyn Solution],
+    days: &Days,
+    options: &RunOptions,
+    mut report: impl FnMut(u8, DayOutcome),
+) {
+    let days: Vec<u8> = days.iter().collect();
+    let run = |day: u8| match solutions.iter().find(|solution| solution.day() == day) {
+        Some(solution) => run_day(*solution, options),
+        None => DayOutcome::Skipped {
+            parts: options.parts.clone(),
+            outcome: Outcome::NotSolved,
+        },
+    };
+
+    if options.jobs <= 1 {
+        for day in days {
+            report(day, run(day));
+        }
+        return;
+    }
+
+    let next_day = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(days.len()) {
+            let (sender, days, next_day, run) = (sender.clone(), &days, &next_day, &run);
+
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(scope, move || {
+                    while let Some(&day) = days.get(next_day.fetch_add(1, Ordering::Relaxed)) {
+                        if sender.send((day, run(day))).is_err() {
+                            break;
+                        }
+                    }
+                })
+                .expect("could not spawn a thread to run days on");
+        }
+        drop(sender);
+
+        // days can finish out of order, hold them back until all earlier days were reported.
+        let mut finished = BTreeMap::new();
+        let mut pending = days.iter().copied().peekable();
+        for (day, outcome) in receiver {
+            finished.insert(day, outcome);
+            while let Some(outcome) = pending.peek().and_then(|day| finished.remove(day)) {
+                report(pending.next().unwrap(), outcome);
+            }
+        }
+    });
+}
+
+/// Message and source location of a caught panic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl Panic {
+    fn from_hook(info: &PanicHookInfo) -> Self {
+        let payload = info.payload();
+        let message = match (
+            payload.downcast_ref::<&str>(),
+            payload.downcast_ref::<String>(),
+        ) {
+            (Some(message), _) => message.to_string(),
+            (_, Some(message)) => message.clone(),
+            _ => "Box<dyn Any>".into(),
+        };
+
+        Panic {
+            message,
+            location: info.location().map(|location| location.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Panic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {}: {}", location, self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    static CAPTURING: Cell<bool> = const { Cell::new(false) };
+    static CAPTURED: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Runs `f`, catching a panic and capturing its message and location instead of printing them to stderr.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CAPTURING.with(Cell::get) {
+                CAPTURED.with(|captured| *captured.borrow_mut() = Some(Panic::from_hook(info)));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let was_capturing = CAPTURING.with(|capturing| capturing.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURING.with(|capturing| capturing.set(was_capturing));
+
+    result.map_err(|_| {
+        CAPTURED
+            .with(|captured| captured.borrow_mut().take())
+            .unwrap_or_else(|| Panic {
+                message: "unknown panic".into(),
+                location: None,
+            })
+    })
+}
+
+/// Runs a single part in a worker process, so that it can be killed once `timeout` has passed.
+/// The worker is the running executable, started with `--worker`. It reports back through [`report::Worker`].
+/// In benchmark mode, `timeout` applies to all runs of the part together.
+/// Returns the day's parse phase, if the worker reported one, followed by the part.
+/// Pass [`BOTH`] as `part` to solve both parts of a day with `solve_both` together.
+pub fn run_worker(day: u8, part: u8, timeout: Duration, options: &RunOptions) -> Vec<PartRun> {
+    let failed = |outcome| {
+        vec![PartRun {
+            part,
+            outcome,
+            variants: Vec::new(),
+        }]
+    };
+
+    let spawned = env::current_exe().and_then(|exe| {
+        Command::new(exe)
+            .args(["--worker", "--days", &day.to_string()])
+            .args(match part {
+                BOTH => vec![],
+                part => vec!["--part".to_string(), part.to_string()],
+            })
+            .args(options.bench.map_or_else(Vec::new, |bench| {
+                vec![
+                    "--bench".to_string(),
+                    "--iterations".to_string(),
+                    bench.iterations.to_string(),
+                    "--warmup".to_string(),
+                    bench.warmup.to_string(),
+                ]
+            }))
+            .args(
+                options
+                    .profile
+                    .iter()
+                    .flat_map(|profile| ["--profile", profile]),
+            )
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+    });
+
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => return failed(worker_failure(format!("could not spawn worker: {}", e))),
+    };
+
+    // drain both pipes while waiting, a full pipe would block the worker.
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() >= deadline => {
+                #[allow(unused_must_use)]
+                {
+                    child.kill();
+                    child.wait();
+                }
+                return failed(Outcome::TimedOut(timeout));
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(5)),
+            Err(e) => return failed(worker_failure(format!("could not wait for worker: {}", e))),
+        }
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let mut parse = None;
+    let mut outcome = None;
+    let mut variants = Vec::new();
+    for record in stdout.lines().filter_map(report::decode_record) {
+        if record.day != day {
+            continue;
+        }
+        match (record.part, record.variant) {
+            (PARSE, None) => parse = Some(record.outcome),
+            (record_part, Some(name)) if record_part == part => {
+                variants.push((name, record.outcome))
+            }
+            (record_part, None) if record_part == part || record_part == BOTH => {
+                outcome = Some((record_part, record.outcome))
+            }
+            _ => {}
+        }
+    }
+
+    let mut runs = Vec::new();
+    if let Some(parse) = parse {
+        let parse_failed = parse.is_failure();
+        runs.push(PartRun {
+            part: PARSE,
+            outcome: parse,
+            variants: Vec::new(),
+        });
+        // the worker did not run the part.
+        if parse_failed {
+            return runs;
+        }
+    }
+
+    let (part, outcome) =
+        outcome.unwrap_or_else(|| (part, worker_failure(crash_message(status, &stderr))));
+    runs.push(PartRun {
+        part,
+        outcome,
+        variants,
+    });
+    runs
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut output = String::new();
+        if let Some(mut pipe) = pipe {
+            #[allow(unused_must_use)]
+            {
+                pipe.read_to_string(&mut output);
+            }
+        }
+        output
+    })
+}
+
+/// Describes a worker that exited without reporting, e.g. after a stack overflow.
+fn crash_message(status: ExitStatus, stderr: &str) -> String {
+    const MAX_LINES: usize = 5;
+
+    let lines: Vec<&str> = stderr.trim_end().lines().collect();
+    if lines.is_empty() {
+        format!("worker exited with {}", status)
+    } else {
+        lines[lines.len().saturating_sub(MAX_LINES)..].join("\n")
+    }
+}
+
+fn worker_failure(message: String) -> Outcome {
+    Outcome::Panicked(Panic {
+        message,
+        location: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!("7".parse(), Ok(Days(vec![7])));
+        assert_eq!("1-3,15, 2".parse(), Ok(Days(vec![1, 2, 3, 15])));
+        assert!("0".parse::<Days>().is_err());
+        assert!("26".parse::<Days>().is_err());
+        assert!("10-1".parse::<Days>().is_err());
+        assert!("1-x".parse::<Days>().is_err());
+    }
+
+    struct Unsolved(u8);
+
+    impl Solution for Unsolved {
+        fn day(&self) -> u8 {
+            self.0
+        }
+
+        fn part_one(&self, _input: Input) -> Outcome {
+            Outcome::NotSolved
+        }
+
+        fn part_two(&self, _input: Input) -> Outcome {
+            Outcome::NotSolved
+        }
+    }
+
+    struct SolvesBoth;
+
+    impl Solution for SolvesBoth {
+        fn day(&self) -> u8 {
+            10
+        }
+
+        fn part_one(&self, _input: Input) -> Outcome {
+            Outcome::NotSolved
+        }
+
+        fn part_two(&self, _input: Input) -> Outcome {
+            Outcome::NotSolved
+        }
+
+        fn solves_both(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_day_parts() {
+        let options = |parts: Vec<u8>| RunOptions {
+            parts,
+            timeout: None,
+            jobs: 1,
+            bench: None,
+            profile: None,
+        };
+
+        assert_eq!(day_parts(&SolvesBoth, &options(vec![1, 2])), [BOTH]);
+        assert_eq!(day_parts(&SolvesBoth, &options(vec![2])), [2]);
+        assert_eq!(day_parts(&Unsolved(3), &options(vec![1, 2])), [1, 2]);
+    }
+
+    #[test]
+    fn test_run_days_in_order() {
+        let solutions: [&dyn Solution; 3] = [&Unsolved(24), &Unsolved(3), &Unsolved(11)];
+        let options = RunOptions {
+            parts: vec![1, 2],
+            timeout: None,
+            jobs: 4,
+            bench: None,
+            profile: None,
+        };
+
+        let mut reported = Vec::new();
+        run_days(&solutions, &Days::all(), &options, |day, outcome| {
+            reported.push((day, outcome))
+        });
+
+        assert_eq!(
+            reported.iter().map(|(day, _)| *day).collect::<Vec<_>>(),
+            (1..=25).collect::<Vec<_>>()
+        );
+        let not_solved = DayOutcome::Skipped {
+            parts: vec![1, 2],
+            outcome: Outcome::NotSolved,
+        };
+        assert_ne!(reported[2].1, not_solved);
+        assert_eq!(reported[3].1, not_solved);
+    }
+
+    #[test]
+    fn test_cross_check() {
+        let solved =
+            |answer: &str| Outcome::Solved(PartResult::new(9, 1, answer, Duration::from_millis(1)));
+
+        let agreeing = [
+            ("a".to_string(), solved("13")),
+            ("b".to_string(), solved("13")),
+        ];
+        assert_eq!(cross_check(&agreeing), solved("13"));
+
+        let disagreeing = [
+            ("a".to_string(), solved("13")),
+            ("b".to_string(), solved("12")),
+            ("c".to_string(), Outcome::NotSolved),
+        ];
+        assert_eq!(
+            cross_check(&disagreeing),
+            Outcome::Mismatch("variants disagree: a = 13, b = 12, c = not solved".into())
+        );
+    }
+
+    #[test]
+    fn test_check_answer() {
+        let solved = |part: u8, answer: &str, answer_two: Option<&str>| {
+            Outcome::Solved(PartResult {
+                answer_two: answer_two.map(Into::into),
+                ..PartResult::new(9, part, answer, Duration::from_millis(1))
+            })
+        };
+        let answers = Answers::from([((9, 1), "13".to_string()), ((9, 2), "1".to_string())]);
+
+        assert_eq!(
+            check_answer(&answers, 9, 1, solved(1, "13", None)),
+            solved(1, "13", None)
+        );
+        assert_eq!(
+            check_answer(&answers, 9, 2, solved(2, "36", None)),
+            Outcome::Mismatch("part 2: expected \"1\", got \"36\"".into())
+        );
+        assert_eq!(
+            check_answer(&answers, 9, BOTH, solved(BOTH, "12", Some("1"))),
+            Outcome::Mismatch("part 1: expected \"13\", got \"12\"".into())
+        );
+        assert_eq!(
+            check_answer(&answers, 10, 1, solved(1, "7", None)),
+            solved(1, "7", None)
+        );
+        assert_eq!(
+            check_answer(&answers, 9, 1, Outcome::NotSolved),
+            Outcome::NotSolved
+        );
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("2"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_timeout("0.5"), Ok(Duration::from_millis(500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("inf").is_err());
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+
+        let panic = catch_panic(|| "x".parse::<u32>().expect("malformed input")).unwrap_err();
+        assert!(panic.message.starts_with("malformed input: "));
+        assert!(panic.location.unwrap().starts_with("src/runner.rs:"));
+
+        let panic = catch_panic(|| panic!("line {}", 3)).unwrap_err();
+        assert_eq!(panic.message, "line 3");
+    }
+}
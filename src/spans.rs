@@ -0,0 +1,164 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Time spent in a named section of a part, see [`span!`](crate::span).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub name: &'static str,
+    /// Total time of all calls.
+    pub elapsed: Duration,
+    pub calls: usize,
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static SPANS: RefCell<Vec<Span>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Starts recording spans, set by `--spans`.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Times the enclosing section of a part until dropped.
+#[doc(hidden)]
+pub struct Guard {
+    name: &'static str,
+    timer: Instant,
+}
+
+impl Guard {
+    /// Returns `None` unless spans are recorded.
+    pub fn enter(name: &'static str) -> Option<Guard> {
+        ENABLED.load(Ordering::Relaxed).then(|| Guard {
+            name,
+            timer: Instant::now(),
+        })
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        record(self.name, self.timer.elapsed());
+    }
+}
+
+fn record(name: &'static str, elapsed: Duration) {
+    SPANS.with(|spans| {
+        add(
+            &mut spans.borrow_mut(),
+            &Span {
+                name,
+                elapsed,
+                calls: 1,
+            },
+        )
+    });
+}
+
+/// Adds `span` to the span of the same name in `spans`, or appends it if there is none.
+fn add(spans: &mut Vec<Span>, span: &Span) {
+    match spans.iter_mut().find(|total| total.name == span.name) {
+        Some(total) => {
+            total.elapsed += span.elapsed;
+            total.calls += span.calls;
+        }
+        None => spans.push(span.clone()),
+    }
+}
+
+/// The spans of repeated runs of a part, e.g. with `--bench`, averaged per run.
+pub fn mean(runs: &[Vec<Span>]) -> Vec<Span> {
+    let mut spans = Vec::new();
+    for span in runs.iter().flatten() {
+        add(&mut spans, span);
+    }
+
+    let count = runs.len().max(1);
+    for span in &mut spans {
+        span.elapsed /= count as u32;
+        span.calls = span.calls.div_ceil(count);
+    }
+    spans
+}
+
+/// Returns the spans recorded on the current thread in the order they were first entered, and clears them.
+pub fn take() -> Vec<Span> {
+    SPANS.with(|spans| spans.take())
+}
+
+/// Times a named section of a part, e.g. `span!("simulate", { rope.run(&moves) })`, and evaluates to its value.
+/// Calls with the same name add up. With `--spans`, `solve!` prints the time per name below the part's result.
+/// Compiles to just the section unless the `spans` feature is enabled.
+#[cfg(feature = "spans")]
+#[macro_export]
+macro_rules! span {
+    ($name:expr, $body:expr) => {{
+        let _span = $crate::spans::Guard::enter($name);
+        $body
+    }};
+}
+
+/// Times a named section of a part, e.g. `span!("simulate", { rope.run(&moves) })`, and evaluates to its value.
+/// Calls with the same name add up. With `--spans`, `solve!` prints the time per name below the part's result.
+/// Compiles to just the section unless the `spans` feature is enabled.
+#[cfg(not(feature = "spans"))]
+#[macro_export]
+macro_rules! span {
+    ($name:expr, $body:expr) => {
+        $body
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        record("parse", Duration::from_micros(5));
+        record("simulate", Duration::from_micros(20));
+        record("parse", Duration::from_micros(3));
+
+        assert_eq!(
+            take(),
+            vec![
+                Span {
+                    name: "parse",
+                    elapsed: Duration::from_micros(8),
+                    calls: 2,
+                },
+                Span {
+                    name: "simulate",
+                    elapsed: Duration::from_micros(20),
+                    calls: 1,
+                },
+            ]
+        );
+        assert_eq!(take(), Vec::new());
+    }
+
+    #[test]
+    fn test_mean() {
+        let span = |name, micros, calls| Span {
+            name,
+            elapsed: Duration::from_micros(micros),
+            calls,
+        };
+
+        assert_eq!(
+            mean(&[
+                vec![span("parse", 4, 1), span("simulate", 30, 2)],
+                vec![span("parse", 6, 1), span("simulate", 10, 2)],
+            ]),
+            vec![span("parse", 5, 1), span("simulate", 20, 2)]
+        );
+        assert_eq!(mean(&[]), Vec::new());
+    }
+}